@@ -0,0 +1,195 @@
+//! Collecting Criterion's latest results into a history file.
+//!
+//! Every `cargo bench` run overwrites Criterion's estimates, so each report appends one JSON line
+//! with the time, commit and every benchmark's estimates, to compare runs over time.
+
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    median: Estimate,
+    std_dev: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub benchmark: String,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+}
+
+#[derive(Serialize)]
+struct Run<'a> {
+    recorded_at: u64,
+    commit: Option<String>,
+    benchmarks: &'a [Record],
+}
+
+/// Finds the latest estimates of every benchmark under `criterion_dir`, sorted by name.
+pub fn collect(criterion_dir: &Path) -> Result<Vec<Record>, String> {
+    let mut records = vec![];
+    let mut dirs = vec![criterion_dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries =
+            fs::read_dir(&dir).map_err(|e| format!("couldn't read {}: {e}", dir.display()))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            if path.ends_with("new") && path.join("estimates.json").exists() {
+                records.push(read_record(&path)?);
+            } else {
+                dirs.push(path);
+            }
+        }
+    }
+
+    records.sort_by(|a, b| a.benchmark.cmp(&b.benchmark));
+    Ok(records)
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {e}", path.display()))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn read_record(dir: &Path) -> Result<Record, String> {
+    let id: BenchmarkId = read_json(&dir.join("benchmark.json"))?;
+    let estimates: Estimates = read_json(&dir.join("estimates.json"))?;
+    Ok(Record {
+        benchmark: id.full_id,
+        mean_ns: estimates.mean.point_estimate,
+        median_ns: estimates.median.point_estimate,
+        std_dev_ns: estimates.std_dev.point_estimate,
+    })
+}
+
+fn current_commit(workspace_dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(workspace_dir)
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Appends the results under `criterion_dir` to `output` as a single line.
+pub fn report(workspace_dir: &Path, criterion_dir: &Path, output: &Path) -> Result<(), String> {
+    let benchmarks = collect(criterion_dir)?;
+    if benchmarks.is_empty() {
+        return Err(format!(
+            "no results in {}, run `cargo bench` first",
+            criterion_dir.display()
+        ));
+    }
+
+    let run = Run {
+        recorded_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        commit: current_commit(workspace_dir),
+        benchmarks: &benchmarks,
+    };
+    let line = serde_json::to_string(&run).map_err(|e| e.to_string())?;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(output)
+        .map_err(|e| format!("couldn't open {}: {e}", output.display()))?;
+    writeln!(file, "{line}").map_err(|e| format!("couldn't write {}: {e}", output.display()))?;
+
+    println!(
+        "Recorded {} benchmarks in {}",
+        benchmarks.len(),
+        output.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_result(criterion_dir: &Path, dir: &str, full_id: &str, mean: f64) {
+        let dir = criterion_dir.join(dir).join("new");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("benchmark.json"),
+            format!(r#"{{"group_id":"g","function_id":null,"full_id":"{full_id}"}}"#),
+        )
+        .unwrap();
+        let estimate = |x: f64| format!(r#"{{"point_estimate":{x},"standard_error":0.1}}"#);
+        fs::write(
+            dir.join("estimates.json"),
+            format!(
+                r#"{{"mean":{},"median":{},"std_dev":{}}}"#,
+                estimate(mean),
+                estimate(mean - 1.0),
+                estimate(2.0)
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_collect() {
+        let criterion_dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        write_result(&criterion_dir, "day06/part1", "day06/part1", 220.0);
+        write_result(
+            &criterion_dir,
+            "day05_lowest_location/lowest_location_intervals",
+            "day05/lowest_location/lowest_location_intervals",
+            1500.0,
+        );
+        fs::create_dir_all(criterion_dir.join("day06/part1/base")).unwrap();
+        fs::create_dir_all(criterion_dir.join("report")).unwrap();
+
+        let records = collect(&criterion_dir);
+        fs::remove_dir_all(&criterion_dir).unwrap();
+
+        assert_eq!(
+            records,
+            Ok(vec![
+                Record {
+                    benchmark: "day05/lowest_location/lowest_location_intervals".to_owned(),
+                    mean_ns: 1500.0,
+                    median_ns: 1499.0,
+                    std_dev_ns: 2.0,
+                },
+                Record {
+                    benchmark: "day06/part1".to_owned(),
+                    mean_ns: 220.0,
+                    median_ns: 219.0,
+                    std_dev_ns: 2.0,
+                },
+            ])
+        );
+    }
+}
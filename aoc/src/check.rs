@@ -0,0 +1,59 @@
+//! Running days against their pinned answers.
+
+use std::panic;
+
+use common::answers::{self, Expected};
+
+use crate::days::Day;
+
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub checked: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+/// Checks every answer pinned for `day`, printing one line per answer.
+///
+/// Answers whose input file is missing are skipped rather than failed.
+pub fn check_day(day: &Day, samples_only: bool, summary: &mut Summary) -> Result<(), String> {
+    let day_dir = crate::day_dir(day.number);
+    let expected = answers::read(&day_dir)?;
+
+    for Expected {
+        input,
+        part,
+        answer,
+    } in expected.iter().filter(|e| e.is_sample() || !samples_only)
+    {
+        let label = format!("Day {:02}, {input}, part {part}", day.number);
+        let Ok(text) = std::fs::read_to_string(day_dir.join(input)) else {
+            println!("{label}: skipped, input missing");
+            summary.skipped += 1;
+            continue;
+        };
+
+        summary.checked += 1;
+        match solve(day, &text, *part) {
+            Ok(actual) if actual == *answer => println!("{label}: ok"),
+            Ok(actual) => {
+                summary.failed += 1;
+                println!("{label}: expected {answer}, got {actual}");
+            }
+            Err(e) => {
+                summary.failed += 1;
+                println!("{label}: expected {answer}, but {e}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn solve(day: &Day, input: &str, part: u8) -> Result<String, String> {
+    match panic::catch_unwind(|| (day.run)(input, Some(part))) {
+        Ok(Ok(mut run)) => Ok(run.answers.remove(0).answer),
+        Ok(Err(e)) => Err(format!("parsing failed: {e}")),
+        Err(_) => Err("it panicked".to_owned()),
+    }
+}
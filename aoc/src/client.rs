@@ -0,0 +1,201 @@
+//! Talking to the Advent of Code website, or to whatever stands in for it at the base URL.
+
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The environment variable holding the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+const YEAR: u32 = 2023;
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    /// A client for the site at `base_url`; requests that need to be logged in fail without a
+    /// `session`.
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            // The site asks automated tools to say what they are
+            .user_agent(concat!(
+                "aoc/",
+                env!("CARGO_PKG_VERSION"),
+                " (Advent of Code runner)"
+            ))
+            .build();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+        }
+    }
+
+    /// A client logged in with the session in [`SESSION_VAR`], if it's set.
+    pub fn from_env(base_url: &str) -> Self {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .map(|session| session.trim().to_owned())
+            .filter(|session| !session.is_empty());
+        Client::new(base_url, session)
+    }
+
+    fn cookie(&self) -> Result<String, String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err(format!(
+                "{SESSION_VAR} isn't set: copy the `session` cookie of a logged in browser into it"
+            )),
+        }
+    }
+
+    fn day_url(&self, day: u32) -> String {
+        format!("{}/{YEAR}/day/{day}", self.base_url)
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, day: u32) -> Result<String, String> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie()?)
+            .call()
+            .map_err(|e| describe(&url, e))?;
+        let input = response
+            .into_string()
+            .map_err(|e| format!("{url}: couldn't read the response: {e}"))?;
+        if input.is_empty() {
+            return Err(format!("{url}: the input is empty"));
+        }
+        Ok(input)
+    }
+
+    /// Sends an answer to a part, returning the page that says how it went.
+    pub fn submit(&self, day: u32, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| describe(&url, e))?;
+        response
+            .into_string()
+            .map_err(|e| format!("{url}: couldn't read the response: {e}"))
+    }
+}
+
+fn describe(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(status, response) => {
+            // The site explains itself in the first line, e.g. that the day hasn't unlocked yet
+            let reason = response.into_string().unwrap_or_default();
+            let reason = reason.lines().next().unwrap_or_default().trim();
+            let hint = match status {
+                400 | 401 | 403 => format!(", check {SESSION_VAR}"),
+                _ => String::new(),
+            };
+            format!("{url}: HTTP {status}{hint}: {reason}")
+        }
+        ureq::Error::Transport(e) => format!("{url}: {e}"),
+    }
+}
+
+/// A local server standing in for the website in tests.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::thread::JoinHandle;
+
+    /// What the stub saw of a request.
+    #[derive(Debug)]
+    pub struct Seen {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// Serves `responses` in order, one per request, then stops and hands back the requests.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Seen>>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = std::thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let mut request = server.recv().unwrap();
+                    let cookie = request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string());
+                    let mut request_body = String::new();
+                    request
+                        .as_reader()
+                        .read_to_string(&mut request_body)
+                        .unwrap();
+                    let seen = Seen {
+                        method: request.method().to_string(),
+                        url: request.url().to_owned(),
+                        cookie,
+                        body: request_body,
+                    };
+                    request
+                        .respond(tiny_http::Response::from_string(body).with_status_code(status))
+                        .unwrap();
+                    seen
+                })
+                .collect()
+        });
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let (base_url, stub) = stub::serve(vec![
+            (200, "1abc2\n"),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!\n",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+        ]);
+        let client = Client::new(&format!("{base_url}/"), Some("53cr3t".to_owned()));
+
+        assert_eq!(client.input(1), Ok("1abc2\n".to_owned()));
+        let e = client.input(25).unwrap_err();
+        assert!(
+            e.contains("HTTP 404: Please don't repeatedly request"),
+            "{e}"
+        );
+        let e = client.input(1).unwrap_err();
+        assert!(e.contains("HTTP 400, check AOC_SESSION"), "{e}");
+
+        let seen = stub.join().unwrap();
+        assert_eq!(seen[0].method, "GET");
+        assert_eq!(seen[0].url, "/2023/day/1/input");
+        assert_eq!(seen[0].cookie.as_deref(), Some("session=53cr3t"));
+        assert_eq!(seen[1].url, "/2023/day/25/input");
+    }
+
+    #[test]
+    fn test_no_session() {
+        // Nothing listens there, and nothing should be sent
+        let client = Client::new("http://127.0.0.1:9", None);
+        assert!(client
+            .input(1)
+            .unwrap_err()
+            .starts_with("AOC_SESSION isn't set"));
+    }
+}
@@ -0,0 +1,122 @@
+use std::error::Error;
+
+use common::Run;
+
+/// Parses the input and runs the given part, or both if `None`.
+type RunFn = fn(&str, Option<u8>) -> Result<Run, Box<dyn Error>>;
+
+/// Makes up an input from a seed, as large as a real one unless a size is given.
+type GenerateFn = fn(u64, Option<usize>) -> String;
+
+pub struct Day {
+    pub number: u32,
+    pub run: RunFn,
+    pub generate: GenerateFn,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: common::run::<day01::Day01>,
+        generate: common::generate::input::<day01::Day01>,
+    },
+    Day {
+        number: 2,
+        run: common::run::<day02::Day02>,
+        generate: common::generate::input::<day02::Day02>,
+    },
+    Day {
+        number: 3,
+        run: common::run::<day03::Day03>,
+        generate: common::generate::input::<day03::Day03>,
+    },
+    Day {
+        number: 4,
+        run: common::run::<day04::Day04>,
+        generate: common::generate::input::<day04::Day04>,
+    },
+    Day {
+        number: 5,
+        run: common::run::<day05::Day05>,
+        generate: common::generate::input::<day05::Day05>,
+    },
+    Day {
+        number: 6,
+        run: common::run::<day06::Day06>,
+        generate: common::generate::input::<day06::Day06>,
+    },
+    Day {
+        number: 7,
+        run: common::run::<day07::Day07>,
+        generate: common::generate::input::<day07::Day07>,
+    },
+    Day {
+        number: 8,
+        run: common::run::<day08::Day08>,
+        generate: common::generate::input::<day08::Day08>,
+    },
+    Day {
+        number: 9,
+        run: common::run::<day09::Day09>,
+        generate: common::generate::input::<day09::Day09>,
+    },
+    Day {
+        number: 10,
+        run: common::run::<day10::Day10>,
+        generate: common::generate::input::<day10::Day10>,
+    },
+    Day {
+        number: 11,
+        run: common::run::<day11::Day11>,
+        generate: common::generate::input::<day11::Day11>,
+    },
+    Day {
+        number: 12,
+        run: common::run::<day12::Day12>,
+        generate: common::generate::input::<day12::Day12>,
+    },
+    Day {
+        number: 13,
+        run: common::run::<day13::Day13>,
+        generate: common::generate::input::<day13::Day13>,
+    },
+    Day {
+        number: 14,
+        run: common::run::<day14::Day14>,
+        generate: common::generate::input::<day14::Day14>,
+    },
+    Day {
+        number: 15,
+        run: common::run::<day15::Day15>,
+        generate: common::generate::input::<day15::Day15>,
+    },
+    Day {
+        number: 16,
+        run: common::run::<day16::Day16>,
+        generate: common::generate::input::<day16::Day16>,
+    },
+    Day {
+        number: 17,
+        run: common::run::<day17::Day17>,
+        generate: common::generate::input::<day17::Day17>,
+    },
+    Day {
+        number: 18,
+        run: common::run::<day18::Day18>,
+        generate: common::generate::input::<day18::Day18>,
+    },
+    Day {
+        number: 19,
+        run: common::run::<day19::Day19>,
+        generate: common::generate::input::<day19::Day19>,
+    },
+    Day {
+        number: 21,
+        run: common::run::<day21::Day21>,
+        generate: common::generate::input::<day21::Day21>,
+    },
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
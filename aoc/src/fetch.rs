@@ -0,0 +1,66 @@
+//! Downloading puzzle inputs once: an input on disk is never asked for again, the site asks not
+//! to be hammered for inputs that never change.
+
+use std::{fs, path::Path};
+
+use crate::client::Client;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input was already there, nothing was sent.
+    Cached,
+}
+
+/// Whether `path` already holds an input; `aoc new` leaves empty placeholders to download into.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Downloads the input of `day` into `path`, unless it's already there.
+pub fn fetch(client: &Client, day: u32, path: &Path) -> Result<Fetched, String> {
+    if is_cached(path) {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.input(day)?;
+    // Written aside and moved into place, so an interrupted write doesn't look like an input
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input)
+        .and_then(|()| fs::rename(&partial, path))
+        .map_err(|e| format!("couldn't write {}: {e}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+
+    #[test]
+    fn test_fetch() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        fs::write(&path, "").unwrap();
+
+        // A single response: a second download would hang the stub
+        let (base_url, stub) = stub::serve(vec![(200, "0 3 6 9 12 15\n")]);
+        let client = Client::new(&base_url, Some("53cr3t".to_owned()));
+        assert_eq!(fetch(&client, 9, &path), Ok(Fetched::Downloaded));
+        assert_eq!(fetch(&client, 9, &path), Ok(Fetched::Cached));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9 12 15\n");
+        assert!(!path.with_extension("partial").exists());
+        assert_eq!(stub.join().unwrap().len(), 1);
+
+        // Failed downloads leave nothing behind
+        let missing = dir.join("missing.txt");
+        let (base_url, stub) = stub::serve(vec![(404, "Not Found")]);
+        let client = Client::new(&base_url, Some("53cr3t".to_owned()));
+        assert!(fetch(&client, 26, &missing).is_err());
+        assert!(!missing.exists());
+        stub.join().unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,243 @@
+//! Fuzzing every day with mutated samples, on stable Rust.
+//!
+//! Each case is a day's sample or small generated input, mutated a few times, fed through parsing
+//! and both parts. Rejecting it with a parse error is fine; panicking, overflowing or running
+//! past the timeout is a failure, and the input is saved to reproduce it with `aoc run --input`.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    panic,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use common::generate::Rng;
+
+use crate::days::Day;
+
+/// Mutated inputs never grow past this, so that every day stays quick on them.
+const MAX_LEN: usize = 4096;
+
+/// Numbers that tend to find overflows and off-by-ones.
+const INTERESTING_NUMBERS: &[&str] = &[
+    "0",
+    "1",
+    "-1",
+    "255",
+    "256",
+    "65536",
+    "2147483647",
+    "2147483648",
+    "-2147483649",
+    "4294967296",
+    "9223372036854775807",
+    "9223372036854775808",
+    "-9223372036854775808",
+    "18446744073709551616",
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub iterations: usize,
+    pub seed: u64,
+    pub timeout: Duration,
+}
+
+#[derive(Debug)]
+enum Outcome {
+    /// Parsed and solved, or rejected with an error: both are fine.
+    Handled,
+    Panicked(String),
+    TimedOut,
+}
+
+/// The day's samples, and a few small generated inputs to reach further past the parser.
+fn corpus(day: &Day) -> Result<Vec<Vec<u8>>, String> {
+    let day_dir = crate::day_dir(day.number);
+    let mut samples = fs::read_dir(&day_dir)
+        .map_err(|e| format!("couldn't read {}: {e}", day_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("sample") && name.ends_with(".txt"))
+        })
+        .collect::<Vec<_>>();
+    // The directory's order isn't stable, and the same seed should fuzz the same cases
+    samples.sort();
+
+    let mut corpus = samples
+        .iter()
+        .map(|path| fs::read(path).map_err(|e| format!("couldn't read {}: {e}", path.display())))
+        .collect::<Result<Vec<_>, _>>()?;
+    corpus.extend((0..3).map(|seed| (day.generate)(seed, Some(5)).into_bytes()));
+    Ok(corpus)
+}
+
+/// A random range of `bytes`, which may be empty.
+fn span(rng: &mut Rng, bytes: &[u8]) -> (usize, usize) {
+    let start = rng.index(bytes.len() + 1);
+    let end = start + rng.index(bytes.len() - start + 1).min(64);
+    (start, end)
+}
+
+/// Applies a few random edits to `input`, mostly with bytes and chunks of the corpus so the
+/// result is likely to get some way into the parser.
+fn mutate(rng: &mut Rng, input: &[u8], corpus: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes = input.to_vec();
+    for _ in 0..rng.between(1, 4) {
+        let donor = rng.choose(corpus);
+        let at = rng.index(bytes.len() + 1);
+        match rng.below(7) {
+            0 if !bytes.is_empty() => {
+                let at = rng.index(bytes.len());
+                bytes[at] = if rng.chance(0.8) && !donor.is_empty() {
+                    *rng.choose(donor)
+                } else {
+                    rng.below(256) as u8
+                };
+            }
+            1 if !donor.is_empty() => bytes.insert(at, *rng.choose(donor)),
+            2 => {
+                let (start, end) = span(rng, &bytes);
+                bytes.drain(start..end);
+            }
+            3 => {
+                let (start, end) = span(rng, &bytes);
+                let chunk = bytes[start..end].to_vec();
+                bytes.splice(at..at, chunk);
+            }
+            4 => {
+                let (start, end) = span(rng, donor);
+                bytes.splice(at..at, donor[start..end].iter().copied());
+            }
+            5 => {
+                // Swap a number, or whatever is there, for one at an edge
+                let end = bytes[at..]
+                    .iter()
+                    .position(|b| !b.is_ascii_digit())
+                    .map_or(bytes.len(), |length| at + length);
+                let number = rng.choose(INTERESTING_NUMBERS).bytes();
+                bytes.splice(at..end, number);
+            }
+            _ => bytes.truncate(at),
+        }
+    }
+    bytes.truncate(MAX_LEN);
+    bytes
+}
+
+/// Runs `input` through the day on another thread, so a hang can be given up on.
+fn run_case(day: &Day, input: &str, timeout: Duration) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let run = day.run;
+    let input = input.to_owned();
+    // A case that hangs is left running: it can't be stopped, but fuzzing stops at the first
+    // failure anyway
+    thread::spawn(move || {
+        let outcome = match panic::catch_unwind(|| run(&input, None)) {
+            Ok(_) => Outcome::Handled,
+            Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+        };
+        sender.send(outcome).ok();
+    });
+
+    receiver.recv_timeout(timeout).unwrap_or(Outcome::TimedOut)
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+/// Cuts chunks out of an input that panics while it keeps panicking, to make it easier to debug.
+fn minimize(day: &Day, input: String, timeout: Duration) -> String {
+    let mut input = input.into_bytes();
+    let mut chunk = input.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < input.len() {
+            let mut candidate = input.clone();
+            candidate.drain(start..(start + chunk).min(input.len()));
+            let candidate_str = String::from_utf8_lossy(&candidate);
+            if let Outcome::Panicked(_) = run_case(day, &candidate_str, timeout) {
+                input = candidate_str.into_owned().into_bytes();
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+    String::from_utf8_lossy(&input).into_owned()
+}
+
+fn save_failure(dir: &Path, day: &Day, input: &str) -> Result<PathBuf, String> {
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    let path = dir
+        .join(format!("day{:02}", day.number))
+        .join(format!("{:016x}.txt", hasher.finish()));
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|()| fs::write(&path, input))
+        .map_err(|e| format!("couldn't save {}: {e}", path.display()))?;
+    Ok(path)
+}
+
+/// Fuzzes `day` for as many cases as asked, stopping at the first failure, which is saved
+/// under `failures_dir`.
+pub fn fuzz_day(day: &Day, options: Options, failures_dir: &Path) -> Result<(), String> {
+    let corpus = corpus(day)?;
+    let mut rng = Rng::new(options.seed ^ u64::from(day.number));
+
+    // Panics are reported once fuzzing stops, not as they happen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failure = (0..options.iterations).find_map(|_| {
+        let base = rng.index(corpus.len());
+        let input = mutate(&mut rng, &corpus[base], &corpus);
+        let input = String::from_utf8_lossy(&input).into_owned();
+        match run_case(day, &input, options.timeout) {
+            Outcome::Handled => None,
+            Outcome::Panicked(message) => Some((
+                format!("panicked: {message}"),
+                minimize(day, input, options.timeout),
+            )),
+            Outcome::TimedOut => Some((format!("took longer than {:?}", options.timeout), input)),
+        }
+    });
+    panic::set_hook(hook);
+
+    match failure {
+        None => Ok(()),
+        Some((reason, input)) => {
+            let path = save_failure(failures_dir, day, &input)?;
+            Err(format!("{reason}, input saved to {}", path.display()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate() {
+        let corpus = vec![b"1 2 3\n4 5 6\n".to_vec(), b"abc".to_vec()];
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            let mutated = mutate(&mut rng, &corpus[0], &corpus);
+            assert!(mutated.len() <= MAX_LEN);
+        }
+        let mutants = (0..10)
+            .map(|_| mutate(&mut rng, &corpus[0], &corpus))
+            .collect::<Vec<_>>();
+        assert!(mutants.iter().any(|mutant| *mutant != corpus[0]));
+    }
+}
@@ -0,0 +1,452 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Parser, Subcommand};
+use common::{input::Source, output::Format};
+
+mod bench_report;
+mod check;
+mod client;
+mod days;
+mod fetch;
+mod fuzz;
+mod scaffold;
+mod submit;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day with `all`
+    Run {
+        /// Day number, or `all`
+        day: Selection,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead of the day's `input.txt`, `-` for stdin
+        #[arg(long, conflicts_with = "sample")]
+        input: Option<PathBuf>,
+
+        /// Use the day's checked-in `sample.txt`, or `sampleN.txt` if N is given
+        #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+        sample: Option<u32>,
+
+        /// Print one JSON record per line and part, with the answer, timings and input hash
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the answers pinned in each day's `answers.txt`
+    Check {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        day: Selection,
+
+        /// Skip the real inputs, some of which are slow to solve
+        #[arg(long)]
+        samples_only: bool,
+    },
+    /// Feed mutated samples through each day, failing on panics, overflows and hangs
+    ///
+    /// Overflows are only caught with overflow checks on, as in the `fuzz` profile:
+    /// `cargo run --profile fuzz -p aoc -- fuzz`
+    Fuzz {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        day: Selection,
+
+        /// Cases to try for each day
+        #[arg(long, default_value_t = 10_000)]
+        iterations: usize,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How long a case can run before it counts as a hang
+        #[arg(long, value_name = "MS", default_value_t = 2000)]
+        timeout_ms: u64,
+    },
+    /// Download a day's input into its `input.txt`, unless it's already there
+    ///
+    /// Logs in with the `session` cookie in `AOC_SESSION`.
+    Fetch {
+        /// Day number
+        day: u32,
+
+        /// Download into this file instead
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Where the site is, e.g. a local stand-in for tests
+        #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Solve a part on the day's input and send the answer, unless it's known to be wrong
+    ///
+    /// Every answer sent and its verdict go into a history file; answers it rules out, and those
+    /// sent before the site's cooldown is over, aren't sent. Logs in like `fetch`.
+    Submit {
+        /// Day number
+        day: u32,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Solve this input instead of the day's `input.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// History file, `submissions.jsonl` by default
+        #[arg(long)]
+        history: Option<PathBuf>,
+
+        /// Where the site is, e.g. a local stand-in for tests
+        #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Start a new day: a crate with stubs, placeholder inputs and an answer to fill in, registered
+    /// in the workspace and here
+    New {
+        /// Day number
+        day: u32,
+    },
+    /// Make up an input for a day, the same one every time for the same seed
+    Generate {
+        /// Day number
+        day: u32,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How large an input to make, in a unit that depends on the day, e.g. lines or the width
+        /// of a map; about as large as a real input by default
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Append the latest `cargo bench` results to a JSON Lines history file
+    BenchReport {
+        /// Criterion's output directory, `target/criterion` by default
+        #[arg(long)]
+        criterion_dir: Option<PathBuf>,
+
+        /// History file to append to, `benchmarks.jsonl` by default
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum Selection {
+    All,
+    Day(u32),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Selection::All),
+            _ => s
+                .parse()
+                .map(Selection::Day)
+                .map_err(|_| format!("expected a day number or `all`, got `{s}`")),
+        }
+    }
+}
+
+fn workspace_dir() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+fn day_dir(day: u32) -> PathBuf {
+    workspace_dir().join(format!("day{day:02}"))
+}
+
+fn run_day(
+    day: &days::Day,
+    part: Option<u8>,
+    source: &Source,
+    format: Format,
+) -> Result<Duration, String> {
+    let input = source.read(&day_dir(day.number))?;
+
+    let run = (day.run)(&input, part).map_err(|e| format!("day {}: {e}", day.number))?;
+    common::output::print(day.number, &input, &run, format);
+
+    Ok(run.total_elapsed())
+}
+
+fn run(
+    selection: Selection,
+    part: Option<u8>,
+    source: Option<Source>,
+    format: Format,
+) -> Result<(), String> {
+    match selection {
+        Selection::Day(number) => {
+            let day = days::find(number).ok_or(format!("day {number} has no solution"))?;
+            let source = source.unwrap_or_else(|| Source::Path(day_dir(number).join("input.txt")));
+            run_day(day, part, &source, format)?;
+        }
+        Selection::All => {
+            if let Some(Source::Path(_) | Source::Stdin) = source {
+                return Err("--input can only be used with a single day".to_owned());
+            }
+
+            let mut total = Duration::ZERO;
+            for day in days::DAYS {
+                let source = source
+                    .clone()
+                    .unwrap_or_else(|| Source::Path(day_dir(day.number).join("input.txt")));
+                total += run_day(day, part, &source, format)?;
+            }
+            // Every record has its own times already
+            if format == Format::Text {
+                println!("Total: {total:?}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check(selection: Selection, samples_only: bool) -> Result<(), String> {
+    let days = match selection {
+        Selection::Day(number) => {
+            vec![days::find(number).ok_or(format!("day {number} has no solution"))?]
+        }
+        Selection::All => days::DAYS.iter().collect(),
+    };
+
+    let mut summary = check::Summary::default();
+    for day in days {
+        if let Err(e) = check::check_day(day, samples_only, &mut summary) {
+            println!("Day {:02}: {e}", day.number);
+            summary.failed += 1;
+        }
+    }
+
+    println!(
+        "{} checked, {} failed, {} skipped",
+        summary.checked, summary.failed, summary.skipped
+    );
+    match summary.failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} answers don't match")),
+    }
+}
+
+fn fuzz(selection: Selection, options: fuzz::Options) -> Result<(), String> {
+    let days = match selection {
+        Selection::Day(number) => {
+            vec![days::find(number).ok_or(format!("day {number} has no solution"))?]
+        }
+        Selection::All => days::DAYS.iter().collect(),
+    };
+
+    let failures_dir = workspace_dir().join("target").join("fuzz");
+    let mut failed = 0;
+    for day in days {
+        match fuzz::fuzz_day(day, options, &failures_dir) {
+            Ok(()) => println!("Day {:02}: ok", day.number),
+            Err(e) => {
+                println!("Day {:02}: {e}", day.number);
+                failed += 1;
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} days failed fuzzing")),
+    }
+}
+
+fn generate(
+    number: u32,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<PathBuf>,
+) -> Result<(), String> {
+    let day = days::find(number).ok_or(format!("day {number} has no solution"))?;
+    let input = (day.generate)(seed, size);
+    match output {
+        Some(path) => std::fs::write(&path, input)
+            .map_err(|e| format!("couldn't write {}: {e}", path.display())),
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
+
+fn fetch(number: u32, output: Option<PathBuf>, base_url: &str) -> Result<(), String> {
+    if !(1..=25).contains(&number) {
+        return Err(format!("there's no day {number} in Advent of Code"));
+    }
+    let path = output.unwrap_or_else(|| day_dir(number).join("input.txt"));
+    let client = client::Client::from_env(base_url);
+    match fetch::fetch(&client, number, &path)? {
+        fetch::Fetched::Downloaded => println!("Downloaded {}", path.display()),
+        fetch::Fetched::Cached => println!(
+            "{} is already there, not downloading it again",
+            path.display()
+        ),
+    }
+    Ok(())
+}
+
+fn submit(
+    number: u32,
+    part: u8,
+    input: Option<PathBuf>,
+    history: &Path,
+    base_url: &str,
+) -> Result<(), String> {
+    let day = days::find(number).ok_or(format!("day {number} has no solution"))?;
+    let path = input.unwrap_or_else(|| day_dir(number).join("input.txt"));
+    let input = Source::Path(path).read(&day_dir(number))?;
+    let run = (day.run)(&input, Some(part)).map_err(|e| format!("day {number}: {e}"))?;
+    let answer = &run.answers[0];
+    if !answer.is_answer {
+        return Err(format!(
+            "day {number} part {part} has no answer to send: {}",
+            answer.answer
+        ));
+    }
+    let answer = &answer.answer;
+
+    let client = client::Client::from_env(base_url);
+    let mut history = submit::History::load(history)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let submission = submit::submit(&client, &mut history, number, part, answer, now)
+        .map_err(|e| format!("day {number} part {part}: {e}"))?;
+
+    let wait = submission
+        .wait_secs
+        .map(|secs| format!(", wait {secs}s before the next answer"))
+        .unwrap_or_default();
+    let verdict = match submission.verdict {
+        submit::Verdict::Correct => {
+            println!("Day {number:02}, part {part}: {answer} is right");
+            return Ok(());
+        }
+        submit::Verdict::TooHigh => "too high",
+        submit::Verdict::TooLow => "too low",
+        submit::Verdict::Wrong => "wrong",
+        submit::Verdict::TooSoon => "not judged, sent too soon",
+        submit::Verdict::WrongLevel => "not judged, the part is solved already or locked",
+    };
+    Err(format!(
+        "day {number} part {part}: {answer} is {verdict}{wait}"
+    ))
+}
+
+fn new(number: u32) -> Result<(), String> {
+    let day_dir = scaffold::new_day(&workspace_dir(), number)?;
+    println!(
+        "Created {}: paste the sample into sample.txt and its answer into answers.txt",
+        day_dir.display()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            sample,
+            json,
+        } => {
+            let source = match (input, sample) {
+                (Some(path), _) if path.as_os_str() == "-" => Some(Source::Stdin),
+                (Some(path), _) => Some(Source::Path(path)),
+                (None, Some(n)) => Some(Source::Sample(Some(n))),
+                (None, None) => None,
+            };
+            let format = if json { Format::Json } else { Format::Text };
+            run(day, part, source, format)
+        }
+        Command::Check { day, samples_only } => check(day, samples_only),
+        Command::Fuzz {
+            day,
+            iterations,
+            seed,
+            timeout_ms,
+        } => fuzz(
+            day,
+            fuzz::Options {
+                iterations,
+                seed,
+                timeout: Duration::from_millis(timeout_ms),
+            },
+        ),
+        Command::Fetch {
+            day,
+            output,
+            base_url,
+        } => fetch(day, output, &base_url),
+        Command::Submit {
+            day,
+            part,
+            input,
+            history,
+            base_url,
+        } => submit(
+            day,
+            part,
+            input,
+            &history.unwrap_or_else(|| workspace_dir().join("submissions.jsonl")),
+            &base_url,
+        ),
+        Command::New { day } => new(day),
+        Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+        } => generate(day, seed, size, output),
+        Command::BenchReport {
+            criterion_dir,
+            output,
+        } => {
+            let workspace = workspace_dir();
+            bench_report::report(
+                &workspace,
+                &criterion_dir.unwrap_or_else(|| workspace.join("target").join("criterion")),
+                &output.unwrap_or_else(|| workspace.join("benchmarks.jsonl")),
+            )
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
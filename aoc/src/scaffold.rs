@@ -0,0 +1,247 @@
+//! Starting a new day from the templates in `aoc/templates`, registered everywhere a day has to
+//! be: the workspace members, `aoc`'s dependencies and its table of days.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files of a new day, relative to its directory, with `{day}` standing for the zero-padded
+/// day and `{number}` for the bare one.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    (
+        "src/generate.rs",
+        include_str!("../templates/generate.rs.tmpl"),
+    ),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (
+        "benches/benchmark.rs",
+        include_str!("../templates/benchmark.rs.tmpl"),
+    ),
+    // Placeholders for the puzzle to be pasted into, and an answer that fails `aoc check` until
+    // the sample's real answer replaces it
+    ("sample.txt", ""),
+    ("input.txt", ""),
+    ("answers.txt", "sample.txt 1 TODO\n"),
+];
+
+type Edit = fn(&str, u32) -> Result<String, String>;
+
+fn fill(template: &str, number: u32) -> String {
+    template
+        .replace("{day}", &format!("{number:02}"))
+        .replace("{number}", &number.to_string())
+}
+
+/// Adds `"dayNN",` to the workspace members, keeping them sorted.
+fn add_member(manifest: &str, number: u32) -> Result<String, String> {
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "members = [")
+        .ok_or("no `members = [` list in the workspace manifest")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("the workspace members list isn't closed")?;
+
+    let entry = format!("    \"day{number:02}\",");
+    let members = &lines[start + 1..end];
+    if members.iter().any(|line| line.trim() == entry.trim()) {
+        return Err(format!("day{number:02} is already a workspace member"));
+    }
+    let at = members
+        .iter()
+        .position(|line| line.trim() > entry.trim())
+        .map_or(end, |i| start + 1 + i);
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `dayNN = { path = "../dayNN" }` to `aoc`'s dependencies, among the other days.
+fn add_dependency(manifest: &str, number: u32) -> Result<String, String> {
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let name = format!("day{number:02}");
+    let day_of = |line: &str| {
+        let (key, _) = line.split_once(" = ")?;
+        key.strip_prefix("day")?.parse::<u32>().ok()
+    };
+    if lines.iter().any(|line| day_of(line) == Some(number)) {
+        return Err(format!("{name} is already a dependency of aoc"));
+    }
+
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect::<Vec<_>>();
+    let at = match days.iter().find(|&&(_, day)| day > number) {
+        Some(&(i, _)) => i,
+        None => days.last().ok_or("aoc doesn't depend on any day yet")?.0 + 1,
+    };
+    let entry = format!("{name} = {{ path = \"../{name}\" }}");
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the table in `days.rs`, in order.
+fn add_day(days: &str, number: u32) -> Result<String, String> {
+    let mut lines = days.lines().collect::<Vec<_>>();
+    let day_of = |line: &str| {
+        line.trim()
+            .strip_prefix("number: ")?
+            .strip_suffix(',')?
+            .parse::<u32>()
+            .ok()
+    };
+    if lines.iter().any(|line| day_of(line) == Some(number)) {
+        return Err(format!("day {number} is already in days.rs"));
+    }
+
+    // Each entry opens with `Day {` on the line before its number
+    let at = match lines.iter().position(|line| day_of(line) > Some(number)) {
+        Some(i) => i - 1,
+        None => lines
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or("no end to the table of days")?,
+    };
+    let entry = fill(
+        "    Day {\n        number: {number},\n        run: common::run::<day{day}::Day{day}>,\n        \
+         generate: common::generate::input::<day{day}::Day{day}>,\n    },",
+        number,
+    );
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `dayNN` under `workspace` and registers it, returning its directory.
+pub fn new_day(workspace: &Path, number: u32) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&number) {
+        return Err(format!("there's no day {number} in Advent of Code"));
+    }
+    let day_dir = workspace.join(format!("day{number:02}"));
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    // Every registration is worked out before anything is written, so a failure leaves no trace
+    let registrations: [(PathBuf, Edit); 3] = [
+        (workspace.join("Cargo.toml"), add_member),
+        (workspace.join("aoc").join("Cargo.toml"), add_dependency),
+        (workspace.join("aoc").join("src").join("days.rs"), add_day),
+    ];
+    let edits = registrations
+        .into_iter()
+        .map(|(path, edit)| {
+            let text = fs::read_to_string(&path)
+                .map_err(|e| format!("couldn't read {}: {e}", path.display()))?;
+            let edited = edit(&text, number).map_err(|e| format!("{}: {e}", path.display()))?;
+            Ok((path, edited))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let files = TEMPLATES
+        .iter()
+        .map(|(path, template)| (day_dir.join(path), fill(template, number)));
+    for (path, text) in files.chain(edits) {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| fs::write(&path, text))
+            .map_err(|e| format!("couldn't write {}: {e}", path.display()))?;
+    }
+
+    Ok(day_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: common::run::<day01::Day01>,
+        generate: common::generate::input::<day01::Day01>,
+    },
+    Day {
+        number: 21,
+        run: common::run::<day21::Day21>,
+        generate: common::generate::input::<day21::Day21>,
+    },
+];
+";
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day21\",\n    \"grid\",\n]\n";
+        assert_eq!(
+            add_member(manifest, 20).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day20\",\n    \"day21\",\n    \"grid\",\n]\n"
+        );
+        assert!(add_member(manifest, 21).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\nclap = \"4\"\nday01 = { path = \"../day01\" }\nday21 = { path = \"../day21\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 20).unwrap(),
+            "[dependencies]\nclap = \"4\"\nday01 = { path = \"../day01\" }\nday20 = { path = \"../day20\" }\nday21 = { path = \"../day21\" }\n"
+        );
+        assert!(add_dependency(manifest, 22)
+            .unwrap()
+            .ends_with("day21 = { path = \"../day21\" }\nday22 = { path = \"../day22\" }\n"));
+        assert!(add_dependency(manifest, 1).is_err());
+    }
+
+    #[test]
+    fn test_add_day() {
+        let days = add_day(DAYS, 20).unwrap();
+        let numbers = days
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("number: "))
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, ["1,", "20,", "21,"]);
+        assert!(days.contains("        run: common::run::<day20::Day20>,\n"));
+
+        let days = add_day(DAYS, 25).unwrap();
+        assert!(days
+            .ends_with("        generate: common::generate::input::<day25::Day25>,\n    },\n];\n"));
+        assert!(add_day(DAYS, 21).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let workspace = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(workspace.join("aoc").join("src")).unwrap();
+        fs::write(
+            workspace.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            workspace.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday01 = { path = \"../day01\" }\n",
+        )
+        .unwrap();
+        fs::write(workspace.join("aoc").join("src").join("days.rs"), DAYS).unwrap();
+
+        let day_dir = new_day(&workspace, 7).unwrap();
+        let read = |path: &Path| fs::read_to_string(path).unwrap();
+        assert!(read(&day_dir.join("Cargo.toml")).contains("name = \"day07bin\""));
+        assert!(read(&day_dir.join("src").join("main.rs")).contains("main::<Day07>(7, "));
+        assert_eq!(read(&day_dir.join("answers.txt")), "sample.txt 1 TODO\n");
+        assert!(read(&workspace.join("Cargo.toml")).contains("    \"day07\",\n"));
+        assert!(read(&workspace.join("aoc").join("src").join("days.rs")).contains("number: 7,"));
+
+        // A second time, and for days that don't exist, nothing is touched
+        assert!(new_day(&workspace, 7).is_err());
+        assert!(new_day(&workspace, 26).is_err());
+        assert!(!workspace.join("day26").exists());
+
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+}
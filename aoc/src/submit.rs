@@ -0,0 +1,347 @@
+//! Submitting answers, and remembering how each went in a history file so that answers already
+//! known to be wrong are never sent again, and cooldowns are waited out.
+//!
+//! The history is JSON Lines, one [`Submission`] per line, appended to after every answer sent.
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// Not judged: an answer to the day was sent too recently.
+    TooSoon,
+    /// Not judged: the part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+    /// How long the site asked to wait before answering the day again, in seconds.
+    pub wait_secs: Option<u64>,
+}
+
+/// Makes out the verdict in the page the site answers with, and how long it asks to wait.
+pub fn judge(page: &str) -> Result<(Verdict, Option<u64>), String> {
+    let verdict = if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("That's not the right answer") {
+        if page.contains("too high") {
+            Verdict::TooHigh
+        } else if page.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        let excerpt = page.split_whitespace().collect::<Vec<_>>().join(" ");
+        let excerpt = excerpt.chars().take(200).collect::<String>();
+        return Err(format!("couldn't make out the verdict in: {excerpt}"));
+    };
+    Ok((verdict, wait_secs(page)))
+}
+
+/// Reads `You have 1m 5s left to wait` and `Please wait one minute before trying again`.
+fn wait_secs(page: &str) -> Option<u64> {
+    if let Some(end) = page.find(" left to wait") {
+        let start = page[..end].rfind("You have ")? + "You have ".len();
+        return page[start..end]
+            .split_whitespace()
+            .map(|amount| {
+                let unit = match amount.chars().last()? {
+                    'h' => 3600,
+                    'm' => 60,
+                    's' => 1,
+                    _ => return None,
+                };
+                Some(amount[..amount.len() - 1].parse::<u64>().ok()? * unit)
+            })
+            .sum();
+    }
+
+    let start = page.find("lease wait ")? + "lease wait ".len();
+    let mut words = page[start..].split_whitespace();
+    let count = match words.next()? {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        count => count.parse().ok()?,
+    };
+    let unit = match words.next()?.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        _ => return None,
+    };
+    Some(count * unit)
+}
+
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Reads the history at `path`, empty if there's no file yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("couldn't read {}: {e}", path.display())),
+        };
+        let submissions = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| format!("{}, line {}: {e}", path.display(), i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(History {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    fn of(&self, day: u32, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day && submission.part == part)
+    }
+
+    /// Checks that sending `answer` at `now` could be any use, given what's already known.
+    pub fn vet(&self, day: u32, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        let known = |verdict: Verdict| {
+            self.of(day, part)
+                .filter(move |submission| submission.verdict == verdict)
+        };
+
+        if let Some(right) = known(Verdict::Correct).next() {
+            return Err(if right.answer == answer {
+                format!("{answer} is already known to be right")
+            } else {
+                format!("the part is already solved, with {}", right.answer)
+            });
+        }
+        if self
+            .of(day, part)
+            .any(|submission| submission.verdict.is_wrong() && submission.answer == answer)
+        {
+            return Err(format!("{answer} is already known to be wrong"));
+        }
+
+        // Numbers can also be ruled out by the hints about others
+        if let Ok(value) = answer.parse::<i128>() {
+            let numbers = |verdict| {
+                known(verdict).filter_map(|submission| submission.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = numbers(Verdict::TooHigh)
+                .filter(|&high| value >= high)
+                .min()
+            {
+                return Err(format!("{answer} is too high, as {high} already is"));
+            }
+            if let Some(low) = numbers(Verdict::TooLow).filter(|&low| value <= low).max() {
+                return Err(format!("{answer} is too low, as {low} already is"));
+            }
+        }
+
+        let wait_until = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day)
+            .filter_map(|submission| Some(submission.submitted_at + submission.wait_secs?))
+            .max();
+        match wait_until {
+            Some(until) if until > now => Err(format!(
+                "the site asked to wait before answering day {day} again, {}s left",
+                until - now
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Appends `submission` to the history file.
+    pub fn record(&mut self, submission: Submission) -> Result<(), String> {
+        let line = serde_json::to_string(&submission).map_err(|e| e.to_string())?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("couldn't open {}: {e}", self.path.display()))?;
+        writeln!(file, "{line}")
+            .map_err(|e| format!("couldn't write {}: {e}", self.path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// Sends `answer` unless the history rules it out, and records how it went.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u32,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Submission, String> {
+    history.vet(day, part, answer, now)?;
+
+    let page = client.submit(day, part, answer)?;
+    let (verdict, wait_secs) = judge(&page)?;
+    let submission = Submission {
+        day,
+        part,
+        answer: answer.to_owned(),
+        verdict,
+        submitted_at: now,
+        wait_secs,
+    };
+    history.record(submission.clone())?;
+    Ok(submission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data. Please wait one minute \
+        before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 5s left to wait. \
+        <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer to \
+        restoring snow operations.</p></article>";
+
+    #[test]
+    fn test_judge() {
+        assert_eq!(judge(TOO_HIGH), Ok((Verdict::TooHigh, Some(60))));
+        assert_eq!(judge(TOO_SOON), Ok((Verdict::TooSoon, Some(65))));
+        assert_eq!(judge(CORRECT), Ok((Verdict::Correct, None)));
+        assert_eq!(
+            judge("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again."),
+            Ok((Verdict::TooLow, Some(300)))
+        );
+        assert_eq!(
+            judge("You don't seem to be solving the right level.  Did you already complete it?"),
+            Ok((Verdict::WrongLevel, None))
+        );
+        assert!(judge("<html>500 Internal Server Error</html>").is_err());
+    }
+
+    fn submission(part: u8, answer: &str, verdict: Verdict, wait_secs: Option<u64>) -> Submission {
+        Submission {
+            day: 1,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+            submitted_at: 1000,
+            wait_secs,
+        }
+    }
+
+    #[test]
+    fn test_vet() {
+        let history = History {
+            path: PathBuf::new(),
+            submissions: vec![
+                submission(1, "100", Verdict::TooHigh, Some(60)),
+                submission(1, "50", Verdict::TooLow, None),
+                submission(1, "abc", Verdict::Wrong, None),
+                submission(2, "7", Verdict::Correct, None),
+            ],
+        };
+
+        assert_eq!(history.vet(1, 1, "75", 2000), Ok(()));
+        assert_eq!(history.vet(1, 1, "def", 2000), Ok(()));
+        assert!(history.vet(1, 1, "abc", 2000).is_err());
+        assert!(history.vet(1, 1, "100", 2000).is_err());
+        assert!(history.vet(1, 1, "150", 2000).is_err());
+        assert!(history.vet(1, 1, "50", 2000).is_err());
+        assert!(history.vet(1, 1, "-3", 2000).is_err());
+        assert!(history.vet(1, 2, "7", 2000).is_err());
+        assert!(history.vet(1, 2, "8", 2000).is_err());
+        // The cooldown holds for both parts of the day, and only that day
+        assert!(history.vet(1, 1, "75", 1059).is_err());
+        assert!(history.vet(1, 1, "75", 1060).is_ok());
+        assert!(history.vet(2, 1, "100", 1001).is_ok());
+    }
+
+    #[test]
+    fn test_submit() {
+        let path = std::env::temp_dir().join(format!("aoc-submit-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let (base_url, stub) = stub::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&base_url, Some("53cr3t".to_owned()));
+        let mut history = History::load(&path).unwrap();
+        let verdict = |submission: Result<Submission, String>| submission.map(|s| s.verdict);
+
+        assert_eq!(
+            verdict(submit(&client, &mut history, 1, 1, "142", 1000)),
+            Ok(Verdict::TooHigh)
+        );
+        // Ruled out without asking, by the history on disk
+        let mut history = History::load(&path).unwrap();
+        assert!(submit(&client, &mut history, 1, 1, "200", 2000).is_err());
+        assert!(submit(&client, &mut history, 1, 1, "77", 1030).is_err());
+        assert_eq!(
+            verdict(submit(&client, &mut history, 1, 1, "77", 2000)),
+            Ok(Verdict::Correct)
+        );
+        assert!(submit(&client, &mut history, 1, 1, "77", 3000).is_err());
+
+        let seen = stub.join().unwrap();
+        assert_eq!(seen[0].method, "POST");
+        assert_eq!(seen[0].url, "/2023/day/1/answer");
+        assert_eq!(seen[0].body, "level=1&answer=142");
+        assert_eq!(seen[1].body, "level=1&answer=77");
+
+        let history = History::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            history.submissions,
+            [
+                submission(1, "142", Verdict::TooHigh, Some(60)),
+                Submission {
+                    submitted_at: 2000,
+                    ..submission(1, "77", Verdict::Correct, None)
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use std::process::Command;
+
+fn check(args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("check")
+        .args(args)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn samples_match_answers() {
+    check(&["all", "--samples-only"]);
+}
+
+#[test]
+#[ignore = "slow, run with `cargo test --release -- --ignored`"]
+fn inputs_match_answers() {
+    check(&["all"]);
+}
@@ -0,0 +1,40 @@
+use std::{fs, process::Command};
+
+#[test]
+fn fetch_downloads_once() {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let stub = std::thread::spawn(move || {
+        let request = server.recv().unwrap();
+        let url = request.url().to_owned();
+        request
+            .respond(tiny_http::Response::from_string("Time: 7\nDistance: 9\n"))
+            .unwrap();
+        url
+    });
+
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-cli-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("input.txt");
+    let fetch = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["fetch", "6", "--output"])
+            .arg(&path)
+            .env("AOC_BASE_URL", &base_url)
+            .env("AOC_SESSION", "53cr3t")
+            .output()
+            .unwrap()
+    };
+
+    let output = fetch();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(stub.join().unwrap(), "/2023/day/6/input");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "Time: 7\nDistance: 9\n");
+
+    // The stub is gone, so this only succeeds without asking
+    let output = fetch();
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already there"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,24 @@
+use std::process::Command;
+
+#[test]
+fn fuzz_every_day_briefly() {
+    // Debug builds check for overflows too, but are slow enough to need a longer timeout
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "fuzz",
+            "all",
+            "--iterations",
+            "200",
+            "--timeout-ms",
+            "10000",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
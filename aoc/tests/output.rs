@@ -0,0 +1,25 @@
+use std::process::Command;
+
+use common::output::Record;
+
+#[test]
+fn run_prints_json_records() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "6", "--sample", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let records = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<Record>(line).unwrap())
+        .collect::<Vec<_>>();
+    let answers = records
+        .iter()
+        .map(|record| (record.day, record.part, record.answer.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(answers, [(6, 1, "288"), (6, 2, "71503")]);
+    assert_eq!(records[0].input_hash, records[1].input_hash);
+    assert_eq!(records[0].parse_ns, records[1].parse_ns);
+}
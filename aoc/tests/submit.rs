@@ -0,0 +1,72 @@
+use std::{fs, process::Command};
+
+#[test]
+fn submit_remembers_wrong_answers() {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let stub = std::thread::spawn(move || {
+        let mut request = server.recv().unwrap();
+        let url = request.url().to_owned();
+        let mut body = String::new();
+        request.as_reader().read_to_string(&mut body).unwrap();
+        request
+            .respond(tiny_http::Response::from_string(
+                "<p>That's not the right answer; your answer is too low.</p>",
+            ))
+            .unwrap();
+        (url, body)
+    });
+
+    let history = std::env::temp_dir().join(format!("aoc-submit-cli-{}.jsonl", std::process::id()));
+    let submit = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["submit", "6", "1", "--input"])
+            .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/../day06/sample.txt"))
+            .arg("--history")
+            .arg(&history)
+            .env("AOC_BASE_URL", &base_url)
+            .env("AOC_SESSION", "53cr3t")
+            .output()
+            .unwrap()
+    };
+
+    let output = submit();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("288 is too low"));
+    assert_eq!(
+        stub.join().unwrap(),
+        (
+            "/2023/day/6/answer".to_owned(),
+            "level=1&answer=288".to_owned()
+        )
+    );
+
+    // The stub is gone: the answer is turned down from the history alone
+    let output = submit();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("288 is already known to be wrong"));
+
+    let lines = fs::read_to_string(&history).unwrap();
+    fs::remove_file(&history).unwrap();
+    assert_eq!(lines.lines().count(), 1);
+    assert!(lines.contains(r#""answer":"288","verdict":"too_low""#));
+}
+
+#[test]
+fn submit_refuses_unsolved_parts() {
+    let history = std::env::temp_dir().join(format!("aoc-unsolved-{}.jsonl", std::process::id()));
+    // Nothing listens there, and nothing should be sent
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "21", "2", "--input"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/../day21/sample.txt"))
+        .arg("--history")
+        .arg(&history)
+        .env("AOC_BASE_URL", "http://127.0.0.1:9")
+        .env("AOC_SESSION", "53cr3t")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("has no answer to send"));
+    assert!(!history.exists());
+}
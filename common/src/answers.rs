@@ -0,0 +1,89 @@
+//! Known answers, pinned so refactors can't silently change results.
+//!
+//! Every day keeps an `answers.txt` next to its inputs with one `<input file> <part> <answer>`
+//! entry per line, e.g. `sample2.txt 1 6`. Blank lines and lines starting with `#` are ignored.
+
+use std::path::Path;
+
+pub const FILE_NAME: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// File name of the input, relative to the day's directory.
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl Expected {
+    pub fn is_sample(&self) -> bool {
+        self.input.starts_with("sample")
+    }
+}
+
+pub fn parse(text: &str) -> Result<Vec<Expected>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let error = |reason: &str| format!("line {}: {reason}", i + 1);
+            let [input, part, answer] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err(error("expected `<input file> <part> <answer>`"));
+            };
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(error("part must be 1 or 2")),
+            };
+
+            Ok(Expected {
+                input: input.to_owned(),
+                part,
+                answer: answer.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Reads the answers file of the day in `day_dir`.
+pub fn read(day_dir: &Path) -> Result<Vec<Expected>, String> {
+    let path = day_dir.join(FILE_NAME);
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("couldn't read {}: {e}", path.display()))?;
+    parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = parse("# input part answer\nsample.txt 1 4361\n\ninput.txt  2 83279367\n");
+        assert_eq!(
+            answers,
+            Ok(vec![
+                Expected {
+                    input: "sample.txt".to_owned(),
+                    part: 1,
+                    answer: "4361".to_owned(),
+                },
+                Expected {
+                    input: "input.txt".to_owned(),
+                    part: 2,
+                    answer: "83279367".to_owned(),
+                },
+            ])
+        );
+        assert!(answers.unwrap()[0].is_sample());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("sample.txt 1 4361\nsample.txt 3 1\n"),
+            Err("line 2: part must be 1 or 2".to_owned())
+        );
+        assert!(parse("sample.txt 1\n").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers for the days' Criterion benchmarks.
+
+use criterion::{black_box, measurement::WallTime, BenchmarkGroup};
+
+use crate::Solution;
+
+/// Benchmarks parsing `input` and solving both parts, as `parse`, `part1` and `part2` in `group`.
+pub fn solution<S: Solution>(group: &mut BenchmarkGroup<WallTime>, input: &str) {
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => panic!("couldn't parse the benchmark input: {e}"),
+    };
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+}
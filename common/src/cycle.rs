@@ -0,0 +1,183 @@
+//! Finding where repeatedly stepping a state starts going round in circles, to skip ahead.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+/// The shape of the sequence of states: after `prefix` steps, it repeats every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The fewest steps that reach the same state as `n` steps do.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Brent's algorithm, which keeps two states and compares them directly.
+    Brent,
+    /// Remembers a hash of every state seen, which steps less but could be fooled by a collision.
+    Fingerprint,
+}
+
+/// Finds the cycle with Brent's algorithm, giving up after `max_steps` steps.
+pub fn brent<S: Clone + Eq>(
+    start: &S,
+    mut step: impl FnMut(&mut S),
+    max_steps: usize,
+) -> Option<Cycle> {
+    // Find the period, moving the tortoise up to the hare at every power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut steps = 1;
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut hare);
+    while tortoise != hare {
+        if steps >= max_steps {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // With the hare a period ahead, they meet at the first repeated state
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..period {
+        step(&mut hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        prefix += 1;
+    }
+
+    Some(Cycle { prefix, period })
+}
+
+fn fingerprint_of<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Finds the cycle by remembering every state's fingerprint, giving up after `max_steps` steps.
+pub fn fingerprint<S: Clone + Hash>(
+    start: &S,
+    mut step: impl FnMut(&mut S),
+    max_steps: usize,
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut state = start.clone();
+    for steps in 0..=max_steps {
+        if let Some(prefix) = seen.insert(fingerprint_of(&state), steps) {
+            return Some(Cycle {
+                prefix,
+                period: steps - prefix,
+            });
+        }
+        step(&mut state);
+    }
+    None
+}
+
+pub fn find<S: Clone + Eq + Hash>(
+    start: &S,
+    step: impl FnMut(&mut S),
+    max_steps: usize,
+    method: Method,
+) -> Option<Cycle> {
+    match method {
+        Method::Brent => brent(start, step, max_steps),
+        Method::Fingerprint => fingerprint(start, step, max_steps),
+    }
+}
+
+/// A state after some number of steps, and the cycle that was skipped to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Iterated<S> {
+    pub state: S,
+    /// Missing when `n` steps came before any state repeated.
+    pub cycle: Option<Cycle>,
+}
+
+/// Steps `start` `n` times, skipping whole cycles once it starts repeating.
+pub fn state_at<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&mut S),
+    n: usize,
+    method: Method,
+) -> Iterated<S> {
+    let cycle = find(&start, &mut step, n, method);
+    let mut state = start;
+    for _ in 0..cycle.map_or(n, |cycle| cycle.reduce(n)) {
+        step(&mut state);
+    }
+    Iterated { state, cycle }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3, 10, 101, 2, 5, 26, 167, 95, 101, ... which repeats from 101 every 6 steps
+    fn step(x: &mut u64) {
+        *x = (*x * *x + 1) % 255;
+    }
+
+    fn naive(mut x: u64, n: usize) -> u64 {
+        for _ in 0..n {
+            step(&mut x);
+        }
+        x
+    }
+
+    #[test]
+    fn test_find() {
+        let expected = Some(Cycle {
+            prefix: 2,
+            period: 6,
+        });
+        assert_eq!(brent(&3, step, 100), expected);
+        assert_eq!(fingerprint(&3, step, 100), expected);
+        assert_eq!(brent(&3, step, 4), None);
+        assert_eq!(fingerprint(&3, step, 4), None);
+        assert_eq!(
+            find(&0u64, |_| {}, 10, Method::Brent),
+            Some(Cycle {
+                prefix: 0,
+                period: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_state_at() {
+        for method in [Method::Brent, Method::Fingerprint] {
+            for n in [0, 1, 2, 7, 8, 9, 50] {
+                assert_eq!(state_at(3, step, n, method).state, naive(3, n));
+            }
+            assert_eq!(state_at(3, step, 1_000_000_000, method).state, 5);
+            assert_eq!(state_at(3, step, 1, method).cycle, None);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Made-up puzzle inputs, so tests and benchmarks don't depend on anyone's personal input.
+//!
+//! Every day's generator builds an input from a seed, so the same seed always gives the same
+//! input, whatever the platform or the version of the crates.
+
+use crate::geometry::{Coord, Direction};
+
+/// A small, seedable random number generator (SplitMix64).
+///
+/// It's here rather than taken from a crate so generated inputs never change under our feet.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "can't pick a number below 0");
+        // Rejecting the top sliver keeps every number equally likely
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {low}..={high}");
+        let span = high.abs_diff(low);
+        match span.checked_add(1) {
+            Some(n) => low.wrapping_add(self.below(n) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// An index into something of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A day that can make up valid inputs for itself.
+pub trait Generator {
+    /// Roughly the size of a real puzzle input, in whatever unit `generate` counts.
+    const REAL_SIZE: usize;
+
+    /// Writes an input whose size grows with `size`, e.g. the number of lines or the width of a
+    /// map, as the day documents.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Generates an input from `seed`, as large as a real one unless a `size` is given.
+pub fn input<G: Generator>(seed: u64, size: Option<usize>) -> String {
+    G::generate(&mut Rng::new(seed), size.unwrap_or(G::REAL_SIZE))
+}
+
+/// A random closed loop through the cells of a `2 * height` by `2 * width` grid, which never
+/// crosses or touches itself. Consecutive cells are neighbours, as are the last and first ones.
+///
+/// It walks around a random tree of about `nodes` cells of a `height` by `width` grid, each of
+/// which is blown up into a 2x2 block of the finer grid.
+pub fn tree_loop(rng: &mut Rng, height: usize, width: usize, nodes: usize) -> Vec<Coord> {
+    assert!(
+        height > 0 && width > 0,
+        "can't grow a tree in an empty grid"
+    );
+    let in_tree = grow_tree(rng, height, width, nodes.clamp(1, height * width));
+
+    // Every cell of a tree node's block links to the next one clockwise, unless a tree edge
+    // leaves the block there, in which case it links straight across into the neighbour's block
+    let node_of = |cell: Coord| Coord::new(cell.row / 2, cell.col / 2);
+    let is_node = |node: Coord| {
+        node.to_pos()
+            .is_some_and(|(row, col)| row < height && col < width && in_tree[row][col])
+    };
+    let next = |cell: Coord| {
+        let clockwise = match (cell.row % 2, cell.col % 2) {
+            (0, 0) => Direction::East,
+            (0, _) => Direction::South,
+            (_, 0) => Direction::North,
+            _ => Direction::West,
+        };
+        // Turning left from the clockwise direction points out of the block
+        let outwards = clockwise.turn_left();
+        let across = cell.step(outwards);
+        if is_node(node_of(across)) && node_of(across) != node_of(cell) {
+            across
+        } else {
+            cell.step(clockwise)
+        }
+    };
+
+    let start = (0..height * width)
+        .map(|i| Coord::new((i / width) as i64, (i % width) as i64))
+        .find(|&node| is_node(node))
+        .map(|node| node * 2)
+        .unwrap();
+    let mut cells = vec![start];
+    let mut cell = next(start);
+    while cell != start {
+        cells.push(cell);
+        cell = next(cell);
+    }
+    cells
+}
+
+/// Grows a random tree from a random cell, returning which cells it spans.
+///
+/// New cells only attach to a single cell of the tree, so the tree never closes a cycle.
+fn grow_tree(rng: &mut Rng, height: usize, width: usize, nodes: usize) -> Vec<Vec<bool>> {
+    let mut in_tree = vec![vec![false; width]; height];
+    let root = Coord::new(rng.index(height) as i64, rng.index(width) as i64);
+    in_tree[root.row as usize][root.col as usize] = true;
+
+    let mut frontier = vec![root];
+    let mut grown = 1;
+    while grown < nodes && !frontier.is_empty() {
+        let from = frontier[rng.index(frontier.len())];
+        let is_tree = |cell: Coord| {
+            cell.to_pos()
+                .is_some_and(|(row, col)| row < height && col < width && in_tree[row][col])
+        };
+        let is_free = |cell: Coord| {
+            cell.to_pos()
+                .is_some_and(|(row, col)| row < height && col < width && !in_tree[row][col])
+        };
+        // Attaching to a second tree cell would close a loop around the cells between them
+        let candidates = Direction::ALL
+            .iter()
+            .map(|&direction| from.step(direction))
+            .filter(|&next| is_free(next))
+            .filter(|&next| {
+                Direction::ALL
+                    .iter()
+                    .filter(|&&direction| is_tree(next.step(direction)))
+                    .count()
+                    == 1
+            })
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            // The tree only grows, so this cell can't ever grow again
+            frontier.retain(|&cell| cell != from);
+            continue;
+        }
+
+        let next = *rng.choose(&candidates);
+        in_tree[next.row as usize][next.col as usize] = true;
+        frontier.push(next);
+        grown += 1;
+    }
+    in_tree
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let sequence = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..10).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(sequence(7), sequence(7));
+        assert_ne!(sequence(7), sequence(8));
+
+        let mut rng = Rng::new(7);
+        for _ in 0..100 {
+            assert!((3..=5).contains(&rng.between(3, 5)));
+            assert!(rng.below(10) < 10);
+        }
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_tree_loop() {
+        for seed in 0..20 {
+            let cells = tree_loop(&mut Rng::new(seed), 6, 8, 30);
+            assert_eq!(cells.len(), cells.iter().collect::<HashSet<_>>().len());
+            assert!(cells.len() >= 4 && cells.len().is_multiple_of(4));
+            for (i, cell) in cells.iter().enumerate() {
+                assert!((0..12).contains(&cell.row) && (0..16).contains(&cell.col));
+                assert_eq!(cell.manhattan(cells[(i + 1) % cells.len()]), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Points and directions on the plane of a 2D map.
+//!
+//! Every day uses the same axes: `row` grows downwards (south) and `col` grows rightwards (east),
+//! so a `Coord` can't be read as `(x, y)` by one day and `(row, col)` by another.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord { row: 0, col: 0 };
+
+    pub const fn new(row: i64, col: i64) -> Self {
+        Coord { row, col }
+    }
+
+    /// The coordinate of a cell of a grid, as returned by its `(row, col)` position.
+    pub fn from_pos((row, col): (usize, usize)) -> Self {
+        Coord::new(row as i64, col as i64)
+    }
+
+    /// The `(row, col)` position of a grid cell, unless either is negative.
+    pub fn to_pos(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+
+    pub fn step(self, direction: impl Into<Direction8>) -> Self {
+        self + direction.into().offset()
+    }
+
+    pub fn step_n(self, direction: impl Into<Direction8>, n: i64) -> Self {
+        self + direction.into().offset() * n
+    }
+
+    /// Steps `n` times towards `direction`, unless that overflows.
+    pub fn checked_step_n(self, direction: impl Into<Direction8>, n: i64) -> Option<Self> {
+        let offset = direction.into().offset();
+        Some(Coord {
+            row: self.row.checked_add(offset.row.checked_mul(n)?)?,
+            col: self.col.checked_add(offset.col.checked_mul(n)?)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Coord) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i64> for Coord {
+    type Output = Coord;
+
+    fn mul(self, n: i64) -> Coord {
+        Coord::new(self.row * n, self.col * n)
+    }
+}
+
+/// Which way to turn, relative to the current heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// One of the four cardinal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// The change in coordinates of a single step.
+    pub fn offset(self) -> Coord {
+        Direction8::from(self).offset()
+    }
+}
+
+/// One of the four cardinal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 6) % 8]
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 2) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The change in coordinates of a single step.
+    pub fn offset(self) -> Coord {
+        let (row, col) = match self {
+            Direction8::North => (-1, 0),
+            Direction8::NorthEast => (-1, 1),
+            Direction8::East => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (1, 0),
+            Direction8::SouthWest => (1, -1),
+            Direction8::West => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+        };
+        Coord::new(row, col)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.turn(Turn::Right), Direction::South);
+        assert_eq!(Direction::South.reverse(), Direction::North);
+        assert_eq!(Direction8::NorthEast.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.turn_right(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthEast.reverse(), Direction8::NorthWest);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.offset(), -direction.reverse().offset());
+        }
+    }
+
+    #[test]
+    fn test_steps() {
+        let coord = Coord::new(2, 3);
+        assert_eq!(coord.step(Direction::North), Coord::new(1, 3));
+        assert_eq!(coord.step(Direction8::SouthWest), Coord::new(3, 2));
+        assert_eq!(coord.step_n(Direction::East, 4), Coord::new(2, 7));
+        assert_eq!(
+            Coord::new(i64::MAX, 0).checked_step_n(Direction::South, 1),
+            None
+        );
+        assert_eq!(coord.manhattan(Coord::new(-1, 5)), 5);
+        assert_eq!(Coord::new(-1, 5).to_pos(), None);
+        assert_eq!(Coord::from_pos((4, 1)).to_pos(), Some((4, 1)));
+    }
+}
@@ -0,0 +1,187 @@
+//! Picking the puzzle input at runtime.
+
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::output::Format;
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    /// One of the sample files checked into the day's directory:
+    /// `sample.txt` for `None` or `Some(1)`, `sampleN.txt` otherwise.
+    Sample(Option<u32>),
+    Stdin,
+}
+
+impl Source {
+    /// Parses the arguments of a day binary: `[PATH | - | --sample [N]]`.
+    /// Without any argument the input is read from stdin.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let source = match args.next().as_deref() {
+            None | Some("-") => Source::Stdin,
+            Some("--sample") => match args.next() {
+                None => Source::Sample(None),
+                Some(n) => n
+                    .parse()
+                    .map(|n| Source::Sample(Some(n)))
+                    .map_err(|_| format!("expected a sample number, got `{n}`"))?,
+            },
+            Some(flag) if flag.starts_with('-') => return Err(format!("unknown option `{flag}`")),
+            Some(path) => Source::Path(path.into()),
+        };
+
+        match args.next() {
+            Some(extra) => Err(format!("unexpected argument `{extra}`")),
+            None => Ok(source),
+        }
+    }
+
+    /// Reads the input, resolving samples relative to `day_dir`.
+    pub fn read(&self, day_dir: &Path) -> Result<String, String> {
+        match self {
+            Source::Path(path) => read_file(path),
+            Source::Sample(n) => read_file(&sample_path(day_dir, *n)),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("couldn't read stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn sample_path(day_dir: &Path, n: Option<u32>) -> PathBuf {
+    match n {
+        None | Some(1) => day_dir.join("sample.txt"),
+        Some(n) => day_dir.join(format!("sample{n}.txt")),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {e}", path.display()))
+}
+
+/// Takes `--json` out of the arguments of a day binary, wherever it is, and picks the format.
+pub fn format_from_args(args: impl IntoIterator<Item = String>) -> (Format, Vec<String>) {
+    let (json, rest): (Vec<_>, Vec<_>) = args.into_iter().partition(|arg| arg == "--json");
+    let format = if json.is_empty() {
+        Format::Text
+    } else {
+        Format::Json
+    };
+    (format, rest)
+}
+
+/// Reads the input picked by the process arguments, and the format they ask the answers in,
+/// exiting with a usage message on failure.
+///
+/// Day binaries call this with their own `CARGO_MANIFEST_DIR` so `--sample` finds their samples.
+pub fn from_args(day_dir: &str) -> (String, Format) {
+    from_args_with(std::env::args().skip(1), "", day_dir)
+}
+
+/// [`from_args`] for days with options of their own, taken out of `args` beforehand and shown as
+/// `options` in the usage message.
+pub fn from_args_with(
+    args: impl IntoIterator<Item = String>,
+    options: &str,
+    day_dir: &str,
+) -> (String, Format) {
+    let (format, args) = format_from_args(args);
+
+    let source = Source::from_args(args).unwrap_or_else(|e| exit_with_usage(options, &e));
+
+    match source.read(Path::new(day_dir)) {
+        Ok(input) => (input, format),
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Exits with `e` and the usage message of a day binary, showing its own `options`.
+pub fn exit_with_usage(options: &str, e: &str) -> ! {
+    let program = std::env::args().next().unwrap_or_default();
+    eprintln!("error: {e}");
+    eprintln!("usage: {program} {options}[--json] [PATH | - | --sample [N]]");
+    std::process::exit(2);
+}
+
+/// Takes `flag` out of `args`, returning whether it was there.
+pub fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let position = args.iter().position(|arg| arg == flag);
+    position.map(|i| args.remove(i)).is_some()
+}
+
+/// Takes `flag` and the value after it out of `args`, returning the value if the flag was there.
+pub fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    if i + 1 == args.len() {
+        return Err(format!("{flag} needs a value"));
+    }
+    Ok(args.drain(i..=i + 1).nth(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Source, String> {
+        Source::from_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(args(&[]), Ok(Source::Stdin));
+        assert_eq!(args(&["-"]), Ok(Source::Stdin));
+        assert_eq!(args(&["--sample"]), Ok(Source::Sample(None)));
+        assert_eq!(args(&["--sample", "3"]), Ok(Source::Sample(Some(3))));
+        assert_eq!(args(&["my.txt"]), Ok(Source::Path("my.txt".into())));
+        assert!(args(&["--sample", "x"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_take_options() {
+        let mut args = ["--bag", "1 red", "--report", "in.txt"]
+            .map(String::from)
+            .to_vec();
+        assert!(take_flag(&mut args, "--report"));
+        assert!(!take_flag(&mut args, "--report"));
+        assert_eq!(take_value(&mut args, "--bag"), Ok(Some("1 red".to_owned())));
+        assert_eq!(take_value(&mut args, "--bag"), Ok(None));
+        assert_eq!(args, ["in.txt"]);
+
+        let mut args = vec!["in.txt".to_owned(), "--bag".to_owned()];
+        assert!(take_value(&mut args, "--bag").is_err());
+    }
+
+    #[test]
+    fn test_format_from_args() {
+        let args = |args: &[&str]| format_from_args(args.iter().map(|s| s.to_string()));
+        assert_eq!(args(&["my.txt"]), (Format::Text, vec!["my.txt".to_owned()]));
+        assert_eq!(
+            args(&["--sample", "--json", "2"]),
+            (Format::Json, vec!["--sample".to_owned(), "2".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_sample_path() {
+        let dir = Path::new("day08");
+        assert_eq!(sample_path(dir, None), dir.join("sample.txt"));
+        assert_eq!(sample_path(dir, Some(1)), dir.join("sample.txt"));
+        assert_eq!(sample_path(dir, Some(3)), dir.join("sample3.txt"));
+    }
+}
@@ -0,0 +1,240 @@
+//! Sets of integers stored as the ranges they cover, for puzzles about far too many values to
+//! handle one by one.
+
+use std::ops::{Add, Range, Sub};
+
+/// A set of values kept as sorted, disjoint, non-adjacent and non-empty half-open ranges, so two
+/// sets holding the same values always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts and merges arbitrary ranges into the normalized form, dropping empty ones.
+    fn normalize(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut intervals: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match intervals.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => intervals.push(range),
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        // The only interval that could hold it is the last one starting at or before it
+        let index = self
+            .intervals
+            .partition_point(|range| range.start <= *value);
+        index > 0 && *value < self.intervals[index - 1].end
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|range| range.start)
+    }
+
+    /// The disjoint ranges making up the set, in increasing order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&IntervalSet::from(range));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                intervals.push(start..end);
+            }
+            // Whichever ends first can't overlap anything further along the other set
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values of `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut removed = other.intervals.iter().peekable();
+        for range in &self.intervals {
+            let mut start = range.start;
+            // Skip what ends before this range, but keep what could reach into the next one
+            while removed.next_if(|hole| hole.end <= start).is_some() {}
+            for hole in removed.clone() {
+                if hole.start >= range.end {
+                    break;
+                }
+                if start < hole.start {
+                    intervals.push(start..hole.start);
+                }
+                start = start.max(hole.end);
+            }
+            if start < range.end {
+                intervals.push(start..range.end);
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Splits into the values below `at` and the values from `at` onwards.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+        for range in &self.intervals {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+        (
+            IntervalSet { intervals: below },
+            IntervalSet { intervals: above },
+        )
+    }
+
+    /// The number of values in the set.
+    pub fn cardinality(&self) -> T
+    where
+        T: Default + Add<Output = T> + Sub<Output = T>,
+    {
+        self.iter().fold(T::default(), |count, range| {
+            count + (range.end - range.start)
+        })
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalize(vec![range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        Self::normalize(ranges.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = std::slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_normalize() {
+        let set: IntervalSet<i32> = [5..8, 0..2, 2..3, 7..10, 12..12].into_iter().collect();
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), vec![0..3, 5..10]);
+        assert_eq!(set.cardinality(), 8);
+        assert_eq!(set.min(), Some(0));
+        assert!(set.contains(&9) && !set.contains(&3) && !set.contains(&10));
+        assert_eq!(IntervalSet::from(4..4), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let set: IntervalSet<i32> = [0..3, 5..10].into_iter().collect();
+        let (below, above) = set.split_at(7);
+        assert_eq!(below, [0..3, 5..7].into_iter().collect());
+        assert_eq!(above, IntervalSet::from(7..10));
+        assert_eq!(
+            set.split_at(3),
+            (IntervalSet::from(0..3), IntervalSet::from(5..10))
+        );
+    }
+
+    /// The same set as a bitset over `0..64`, which is easy to trust.
+    fn model(set: &IntervalSet<u32>) -> u64 {
+        set.iter()
+            .flat_map(|range| range.clone())
+            .fold(0, |bits, value| bits | 1 << value)
+    }
+
+    fn assert_normalized(set: &IntervalSet<u32>) {
+        assert!(set.iter().all(|range| range.start < range.end));
+        assert!(set
+            .intervals
+            .windows(2)
+            .all(|pair| pair[0].end < pair[1].start));
+    }
+
+    fn interval_set() -> impl Strategy<Value = IntervalSet<u32>> {
+        prop::collection::vec((0..64u32, 0..=64u32), 0..6)
+            .prop_map(|bounds| bounds.into_iter().map(|(start, end)| start..end).collect())
+    }
+
+    proptest! {
+        #[test]
+        fn test_against_bitset(a in interval_set(), b in interval_set(), at in 0..=64u32) {
+            for set in [&a, &b] {
+                assert_normalized(set);
+                prop_assert_eq!(set.cardinality(), model(set).count_ones());
+                for value in 0..64 {
+                    prop_assert_eq!(set.contains(&value), model(set) & 1 << value != 0);
+                }
+            }
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+            for set in [&union, &intersection, &difference] {
+                assert_normalized(set);
+            }
+            prop_assert_eq!(model(&union), model(&a) | model(&b));
+            prop_assert_eq!(model(&intersection), model(&a) & model(&b));
+            prop_assert_eq!(model(&difference), model(&a) & !model(&b));
+
+            let (below, above) = a.split_at(at);
+            assert_normalized(&below);
+            assert_normalized(&above);
+            let mask = 1u64.checked_shl(at).map_or(u64::MAX, |bit| bit - 1);
+            prop_assert_eq!(model(&below), model(&a) & mask);
+            prop_assert_eq!(model(&above), model(&a) & !mask);
+        }
+    }
+}
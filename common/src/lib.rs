@@ -0,0 +1,175 @@
+pub mod answers;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod cycle;
+pub mod generate;
+pub mod geometry;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod output;
+mod parse;
+
+pub use parse::{Location, ParseError};
+
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// A day's puzzle: the input is parsed once and both parts are answered from it.
+pub trait Solution {
+    /// The parsed input, which may borrow from the raw input.
+    type Parsed<'a>;
+    type Error: std::error::Error + 'static;
+    type Answer1: PartAnswer;
+    type Answer2: PartAnswer;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::Answer1;
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::Answer2;
+}
+
+/// What a part answers with, which may say that there's no answer rather than be one.
+pub trait PartAnswer: Display {
+    /// Whether this is an answer to the puzzle, e.g. one worth submitting.
+    fn is_answer(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_part_answer {
+    ($($t:ty),*) => {
+        $(impl PartAnswer for $t {})*
+    };
+}
+
+impl_part_answer!(i32, i64, i128, isize, u32, u64, u128, usize, String);
+
+/// Answer for a part that hasn't been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// Answer for a part that a well-formed input doesn't always have, e.g. because it overflows or
+/// the puzzle's promises about the input don't hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for Maybe<T> {
+    fn from(answer: Option<T>) -> Self {
+        Maybe(answer)
+    }
+}
+
+impl PartAnswer for Unsolved {
+    fn is_answer(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Display> PartAnswer for Maybe<T> {
+    fn is_answer(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => write!(f, "no answer"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    /// Whether `answer` is one, see [`PartAnswer::is_answer`].
+    pub is_answer: bool,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Run {
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.answers.iter().map(|a| a.elapsed).sum::<Duration>()
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Times `solve`, which answers `part`.
+fn answer<A: PartAnswer>(part: u8, solve: impl FnOnce() -> A) -> Answer {
+    let ((answer, is_answer), elapsed) = timed(|| {
+        let answer = solve();
+        (answer.to_string(), answer.is_answer())
+    });
+    Answer {
+        part,
+        answer,
+        is_answer,
+        elapsed,
+    }
+}
+
+/// Parses `input` and runs the requested part, or both parts if `part` is `None`.
+pub fn run<S: Solution>(input: &str, part: Option<u8>) -> Result<Run, Box<dyn std::error::Error>> {
+    let (parsed, parse_elapsed) = timed(|| S::parse(input));
+    let parsed = parsed?;
+
+    let mut answers = vec![];
+    if part.is_none_or(|part| part == 1) {
+        answers.push(answer(1, || S::part1(&parsed)));
+    }
+    if part.is_none_or(|part| part == 2) {
+        answers.push(answer(2, || S::part2(&parsed)));
+    }
+
+    Ok(Run {
+        parse_elapsed,
+        answers,
+    })
+}
+
+/// A part of a day as [`run_parts`] takes it: its number, and how to answer it from the parsed
+/// input.
+pub type Part<'a, P, A> = (u8, &'a dyn Fn(&P) -> A);
+
+/// Times `parse`, then each part on what it parsed, for day binaries whose parts take more than
+/// the [`Solution`] does, like options.
+pub fn run_parts<P, A: PartAnswer>(parse: impl FnOnce() -> P, parts: &[Part<P, A>]) -> Run {
+    let (parsed, parse_elapsed) = timed(parse);
+    let answers = parts
+        .iter()
+        .map(|&(part, solve)| answer(part, || solve(&parsed)))
+        .collect();
+    Run {
+        parse_elapsed,
+        answers,
+    }
+}
+
+/// Parses `input` for a day binary, exiting with the error message if it's malformed.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Parsed<'_> {
+    S::parse(input).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    })
+}
@@ -0,0 +1,249 @@
+//! Number theory and exact arithmetic that keeps coming back in the puzzles.
+
+use num::{integer::Integer, rational::Ratio};
+
+/// The greatest common divisor of all the values, or zero if there are none.
+pub fn gcd_all<T: Integer + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |gcd, value| gcd.gcd(value))
+}
+
+/// The least common multiple of all the values, or one if there are none.
+pub fn lcm_all<T: Integer + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::one(), |lcm, value| lcm.lcm(value))
+}
+
+/// Integer square roots that are exact however large the number, unlike going through `f64`.
+pub trait IntegerSqrt: Sized {
+    /// The largest root whose square is at most `self`, unless `self` is negative.
+    fn floor_sqrt(self) -> Option<Self>;
+
+    /// The root, if `self` is a perfect square.
+    fn exact_sqrt(self) -> Option<Self>;
+}
+
+impl IntegerSqrt for i64 {
+    fn floor_sqrt(self) -> Option<Self> {
+        self.checked_isqrt()
+    }
+
+    fn exact_sqrt(self) -> Option<Self> {
+        self.floor_sqrt().filter(|root| root * root == self)
+    }
+}
+
+impl IntegerSqrt for i128 {
+    fn floor_sqrt(self) -> Option<Self> {
+        self.checked_isqrt()
+    }
+
+    fn exact_sqrt(self) -> Option<Self> {
+        self.floor_sqrt().filter(|root| root * root == self)
+    }
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+        (g, y, x - a.div_euclid(b) * y)
+    }
+}
+
+/// The values congruent to `residue` modulo `modulus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Congruence {
+    /// Always in `0..modulus`.
+    pub residue: i64,
+    pub modulus: i64,
+}
+
+impl Congruence {
+    /// Any offset works as the residue, e.g. `-1` is the same as `modulus - 1`.
+    ///
+    /// Panics if the modulus isn't positive.
+    pub fn new(residue: i64, modulus: i64) -> Self {
+        assert!(modulus > 0, "modulus must be positive, got {modulus}");
+        Congruence {
+            residue: residue.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        value.rem_euclid(self.modulus) == self.residue
+    }
+
+    /// The values satisfying both congruences, unless none do or their modulus overflows.
+    ///
+    /// The moduli don't need to be coprime.
+    pub fn combine(&self, other: &Congruence) -> Option<Congruence> {
+        let (m1, m2) = (self.modulus as i128, other.modulus as i128);
+        let (g, p, _) = extended_gcd(m1, m2);
+        let difference = other.residue as i128 - self.residue as i128;
+        if difference % g != 0 {
+            return None;
+        }
+
+        // Solve m1 * k ≡ difference (mod m2), where p inverts m1 / g modulo m2 / g
+        let lcm = m1 / g * m2;
+        let k = (difference / g * p).rem_euclid(m2 / g);
+        let residue = (self.residue as i128 + m1 * k).rem_euclid(lcm);
+        let modulus = i64::try_from(lcm).ok()?;
+        Some(Congruence::new(residue as i64, modulus))
+    }
+
+    /// The smallest value satisfying the congruence that is at least `min`, unless it overflows.
+    pub fn first_at_least(&self, min: i64) -> Option<i64> {
+        let offset = (self.residue as i128 - min as i128).rem_euclid(self.modulus as i128);
+        i64::try_from(min as i128 + offset).ok()
+    }
+}
+
+/// The values satisfying every congruence, by the Chinese remainder theorem, unless none do or
+/// their modulus overflows.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), |all, congruence| {
+            all.combine(&congruence)
+        })
+}
+
+pub type Rational = Ratio<i128>;
+
+/// The polynomial through some points in Newton's form, which stays exact by keeping rational
+/// coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewtonPolynomial {
+    xs: Vec<i128>,
+    coefficients: Vec<Rational>,
+}
+
+impl NewtonPolynomial {
+    /// Fits the polynomial of the lowest degree through the points, by divided differences.
+    ///
+    /// Panics if two points share an `x`.
+    pub fn fit(points: &[(i128, i128)]) -> Self {
+        let xs = points.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+        let mut differences = points
+            .iter()
+            .map(|&(_, y)| Rational::from_integer(y))
+            .collect::<Vec<_>>();
+
+        // After round `k`, entry `i >= k` holds the divided difference of points `i - k..=i`
+        for k in 1..points.len() {
+            for i in (k..points.len()).rev() {
+                let dx = xs[i] - xs[i - k];
+                assert_ne!(dx, 0, "two points at x = {}", xs[i]);
+                differences[i] = (differences[i] - differences[i - 1]) / dx;
+            }
+        }
+
+        NewtonPolynomial {
+            xs,
+            coefficients: differences,
+        }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|c| *c != Rational::default())
+            .unwrap_or(0)
+    }
+
+    pub fn evaluate(&self, x: i128) -> Rational {
+        // Horner's rule, nested the Newton way: c0 + (x - x0) * (c1 + (x - x1) * (...))
+        self.coefficients
+            .iter()
+            .zip(&self.xs)
+            .rev()
+            .fold(Rational::default(), |acc, (c, xi)| c + acc * (x - xi))
+    }
+}
+
+/// The value at `x` of the polynomial through the points, by Lagrange's formula.
+///
+/// Panics if two points share an `x`.
+pub fn lagrange(points: &[(i128, i128)], x: i128) -> Rational {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(xi, yi))| {
+            points.iter().enumerate().filter(|&(j, _)| j != i).fold(
+                Rational::from_integer(yi),
+                |term, (_, &(xj, _))| {
+                    assert_ne!(xi, xj, "two points at x = {xi}");
+                    term * Rational::new(x - xj, xi - xj)
+                },
+            )
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd_all(&[12, 18, 30]), 6);
+        assert_eq!(gcd_all::<i32>(&[]), 0);
+        assert_eq!(lcm_all(&[4u64, 6, 10]), 60);
+        assert_eq!(lcm_all::<u64>(&[]), 1);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(99i64.floor_sqrt(), Some(9));
+        assert_eq!(100i64.exact_sqrt(), Some(10));
+        assert_eq!(99i64.exact_sqrt(), None);
+        assert_eq!((-1i64).floor_sqrt(), None);
+        // Past 2^53, where f64 can't tell these apart
+        let root = 3_037_000_499i64;
+        assert_eq!((root * root - 1).floor_sqrt(), Some(root - 1));
+        assert_eq!((root * root).exact_sqrt(), Some(root));
+        let root = 10i128.pow(18) + 7;
+        assert_eq!((root * root + root).floor_sqrt(), Some(root));
+    }
+
+    #[test]
+    fn test_crt() {
+        let solved = crt([
+            Congruence::new(2, 3),
+            Congruence::new(3, 5),
+            Congruence::new(2, 7),
+        ]);
+        assert_eq!(solved, Some(Congruence::new(23, 105)));
+
+        // Not coprime, with and without a solution
+        let x = Congruence::new(3, 4).combine(&Congruence::new(-1, 6));
+        assert_eq!(x, Some(Congruence::new(11, 12)));
+        assert_eq!(Congruence::new(0, 4).combine(&Congruence::new(1, 6)), None);
+
+        let solved = Congruence::new(11, 12);
+        assert_eq!(solved.first_at_least(0), Some(11));
+        assert_eq!(solved.first_at_least(12), Some(23));
+        assert_eq!(solved.first_at_least(-5), Some(-1));
+        assert_eq!(solved.first_at_least(i64::MAX), None);
+
+        let large = Congruence::new(0, 1 << 40);
+        assert_eq!(large.combine(&Congruence::new(1, (1 << 40) - 1)), None);
+        assert!(solved.contains(-13));
+    }
+
+    #[test]
+    fn test_interpolation() {
+        // y = x^2 / 2 - 3, which has rational coefficients but integer values at even x
+        let points = [(0, -3), (2, -1), (4, 5), (6, 15)];
+        let polynomial = NewtonPolynomial::fit(&points);
+        assert_eq!(polynomial.degree(), 2);
+        assert_eq!(polynomial.evaluate(8), Rational::from_integer(29));
+        assert_eq!(polynomial.evaluate(1), Rational::new(-5, 2));
+        for x in -5..10 {
+            assert_eq!(polynomial.evaluate(x), lagrange(&points, x));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Printing a day's answers, either for people or as JSON Lines for dashboards and CI.
+//!
+//! Every day binary and `aoc run` print through here, so the output looks the same whichever ran.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Run, Solution};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Day 08, part 1: 19199 (123µs)`, one line per part after the parse time.
+    #[default]
+    Text,
+    /// One JSON [`Record`] per line and part.
+    Json,
+}
+
+/// A part's answer and what it took, for machines to read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    /// Parsing is shared by both parts, so both records have the same parse time.
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Tells apart the answers to different inputs, see [`input_hash`].
+    pub input_hash: String,
+}
+
+/// A hash of the input that stays the same across platforms and Rust versions (64-bit FNV-1a),
+/// as 16 hex digits.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+pub fn records(day: u32, input: &str, run: &Run) -> Vec<Record> {
+    let input_hash = input_hash(input);
+    run.answers
+        .iter()
+        .map(|answer| Record {
+            day,
+            part: answer.part,
+            answer: answer.answer.clone(),
+            parse_ns: run.parse_elapsed.as_nanos() as u64,
+            solve_ns: answer.elapsed.as_nanos() as u64,
+            input_hash: input_hash.clone(),
+        })
+        .collect()
+}
+
+pub fn print(day: u32, input: &str, run: &Run, format: Format) {
+    match format {
+        Format::Text => {
+            println!("Day {day:02}, parse: {:?}", run.parse_elapsed);
+            for answer in &run.answers {
+                println!(
+                    "Day {day:02}, part {}: {} ({:?})",
+                    answer.part, answer.answer, answer.elapsed
+                );
+            }
+        }
+        Format::Json => {
+            for record in records(day, input, run) {
+                // Plain strings and numbers always serialize
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+}
+
+/// Runs both parts on `input` and prints them, exiting with the error message if it's malformed.
+pub fn run_and_print<S: Solution>(day: u32, input: &str, format: Format) {
+    match crate::run::<S>(input, None) {
+        Ok(run) => print(day, input, &run, format),
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// The whole of a day binary: reads the input picked by the arguments and prints both parts.
+pub fn main<S: Solution>(day: u32, day_dir: &str) {
+    let (input, format) = crate::input::from_args(day_dir);
+    run_and_print::<S>(day, &input, format);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_input_hash() {
+        // Reference values of 64-bit FNV-1a
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1\n"), input_hash("1\r\n"));
+    }
+
+    #[test]
+    fn test_records() {
+        let run = Run {
+            parse_elapsed: Duration::from_micros(3),
+            answers: vec![
+                Answer {
+                    part: 1,
+                    answer: "42".to_owned(),
+                    is_answer: true,
+                    elapsed: Duration::from_nanos(1500),
+                },
+                Answer {
+                    part: 2,
+                    answer: "no answer".to_owned(),
+                    is_answer: false,
+                    elapsed: Duration::from_nanos(20),
+                },
+            ],
+        };
+
+        let records = records(8, "a", &run);
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0],
+            Record {
+                day: 8,
+                part: 1,
+                answer: "42".to_owned(),
+                parse_ns: 3000,
+                solve_ns: 1500,
+                input_hash: "af63dc4c8601ec8c".to_owned(),
+            }
+        );
+        assert_eq!(records[1].parse_ns, 3000);
+
+        let json = serde_json::to_string(&records[1]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"day":8,"part":2,"answer":"no answer","parse_ns":3000,"solve_ns":20,"input_hash":"af63dc4c8601ec8c"}"#
+        );
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), records[1]);
+    }
+}
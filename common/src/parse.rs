@@ -0,0 +1,146 @@
+//! Errors for puzzle inputs that can't be parsed.
+
+use std::fmt::Display;
+
+/// Position in the input, both counted from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Finds where `token` starts within `input`, if it's a slice of it.
+    pub fn of(input: &str, token: &str) -> Option<Self> {
+        let offset = (token.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+        if offset > input.len() {
+            return None;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        })
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A token of the input that couldn't be parsed, and why.
+///
+/// Parsers only see the piece of input they're parsing, so errors are created from the
+/// offending token alone and placed in the whole input afterwards with [`ParseError::locate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub kind: K,
+    pub token: String,
+    pub location: Option<Location>,
+    address: usize,
+}
+
+impl<K> ParseError<K> {
+    /// `token` should be a slice of the input so the error can be located later.
+    pub fn new(token: &str, kind: K) -> Self {
+        ParseError {
+            kind,
+            token: token.to_owned(),
+            location: None,
+            address: token.as_ptr() as usize,
+        }
+    }
+
+    /// An error about the input as a whole rather than any token in it, which is never located.
+    pub fn whole_input(kind: K) -> Self {
+        ParseError {
+            kind,
+            token: String::new(),
+            location: None,
+            address: 0,
+        }
+    }
+
+    /// Works out the line and column of the token within `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        if self.location.is_none() {
+            let offset = self.address.wrapping_sub(input.as_ptr() as usize);
+            self.location = input
+                .get(offset..)
+                .and_then(|token| Location::of(input, token));
+        }
+        self
+    }
+}
+
+impl<K: Display> Display for ParseError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = self.location {
+            write!(f, "{location}: ")?;
+        }
+        write!(f, "{}", self.kind)?;
+        if !self.token.is_empty() {
+            write!(f, " (`{}`)", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: std::fmt::Debug + Display> std::error::Error for ParseError<K> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_of() {
+        let input = "ab\ncdé f\n";
+        assert_eq!(
+            Location::of(input, &input[0..]),
+            Some(Location { line: 1, column: 1 })
+        );
+        assert_eq!(
+            Location::of(input, &input[3..]),
+            Some(Location { line: 2, column: 1 })
+        );
+        assert_eq!(
+            Location::of(input, &input[8..]),
+            Some(Location { line: 2, column: 5 })
+        );
+        assert_eq!(
+            Location::of(input, &input[10..]),
+            Some(Location { line: 3, column: 1 })
+        );
+        assert_eq!(Location::of(input, &String::from("ab")), None);
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "Game 1: 3 blue\nGame 2: 4 purple\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::new(&line[10..], "unknown color").locate(input);
+
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 2,
+                column: 11
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 11: unknown color (`purple`)"
+        );
+    }
+
+    #[test]
+    fn test_locate_foreign_token() {
+        let error = ParseError::new("purple", "unknown color").locate("Game 1: 3 blue");
+        assert_eq!(error.location, None);
+        assert_eq!(error.to_string(), "unknown color (`purple`)");
+    }
+}
@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day01::Day01;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day01>(0, None);
+    common::bench::solution::<Day01>(&mut c.benchmark_group("day01"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,35 @@
+//! Made-up calibration documents, `size` lines long.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day01;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+impl Generator for Day01 {
+    const REAL_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut line = String::new();
+            // Every line gets a digit somewhere, and digits spelled out or not between letters
+            let digit_at = rng.between(0, 5);
+            for i in 0..=rng.between(digit_at, 8) {
+                if i == digit_at || rng.chance(0.2) {
+                    line.push(char::from(b'1' + rng.below(9) as u8));
+                } else if rng.chance(0.3) {
+                    let word = rng.choose(&WORDS);
+                    line.push_str(word);
+                } else {
+                    line.push(char::from(b'a' + rng.below(26) as u8));
+                }
+            }
+            input.push_str(&line);
+            input.push('\n');
+        }
+        input
+    }
+}
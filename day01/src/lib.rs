@@ -0,0 +1,395 @@
+use std::{convert::Infallible, num::ParseIntError};
+
+use common::Solution;
+
+mod generate;
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VocabularyErrorKind {
+    #[error("expected a word and its value")]
+    MissingValue,
+    #[error("invalid value: {0}")]
+    InvalidValue(#[from] ParseIntError),
+    #[error("expected a single word and its value")]
+    TrailingText,
+    #[error("the word is already in the vocabulary")]
+    DuplicateWord,
+}
+
+pub type VocabularyError = common::ParseError<VocabularyErrorKind>;
+
+/// The tokens that stand for digits, and their values.
+///
+/// The digits `0` to `9` always do; words can stand for any value, a calibration value is still
+/// ten times the first plus the last, so `ten` then `3` make 103.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    /// In the order they were listed, for [`Matching::First`].
+    tokens: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Only the digits, as in part 1.
+    pub fn digits() -> Self {
+        let tokens = (0..10).map(|digit| (digit.to_string(), digit)).collect();
+        Vocabulary { tokens }
+    }
+
+    /// The digits and `one` to `nine` spelled out, as in part 2.
+    pub fn english() -> Self {
+        let mut vocabulary = Vocabulary::digits();
+        let words = ENGLISH.map(|(word, value)| (word.to_owned(), value));
+        vocabulary.tokens.extend(words);
+        vocabulary
+    }
+
+    /// The digits and the words listed in `text`, one `word value` pair per line. Blank lines
+    /// and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Self, VocabularyError> {
+        let mut vocabulary = Vocabulary::digits();
+        for line in text.lines() {
+            let mut fields = line.split_whitespace();
+            let Some(word) = fields.next().filter(|word| !word.starts_with('#')) else {
+                continue;
+            };
+            let error = |token, kind| VocabularyError::new(token, kind).locate(text);
+
+            let value = fields
+                .next()
+                .ok_or_else(|| error(line, VocabularyErrorKind::MissingValue))?;
+            let value = value
+                .parse()
+                .map_err(|e| error(value, VocabularyErrorKind::InvalidValue(e)))?;
+            if let Some(extra) = fields.next() {
+                return Err(error(extra, VocabularyErrorKind::TrailingText));
+            }
+            if vocabulary.tokens.iter().any(|(token, _)| token == word) {
+                return Err(error(word, VocabularyErrorKind::DuplicateWord));
+            }
+            vocabulary.tokens.push((word.to_owned(), value));
+        }
+        Ok(vocabulary)
+    }
+}
+
+/// How to pick between tokens starting at the same place, like `seven` and `seventeen`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Matching {
+    /// The longest of them.
+    #[default]
+    Longest,
+    /// The one listed first in the vocabulary, as alternatives in a regex would.
+    First,
+}
+
+/// A token found on a line, spanning bytes `start..end` of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// The tokens a line's calibration value is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Token,
+    /// The same as `first` if there's only one token on the line.
+    pub last: Token,
+    /// Whether the last token shares letters with the one before it, as in `twone`.
+    pub last_overlaps: bool,
+}
+
+impl Calibration {
+    pub fn value(&self) -> u64 {
+        10 * u64::from(self.first.value) + u64::from(self.last.value)
+    }
+
+    /// Why the value might not be the one meant, if it might not.
+    pub fn suspicion(&self) -> Option<&'static str> {
+        self.last_overlaps
+            .then_some("the last digit shares letters with the one before it")
+    }
+}
+
+/// Why a line has no calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidLine {
+    #[error("the line is empty")]
+    Empty,
+    #[error("no digit on the line")]
+    NoDigit,
+}
+
+/// Finds the first and last digit of a line in a single pass from the front.
+///
+/// A token is looked for at every position, so words sharing letters, like `twone` or
+/// `eightwo`, are both found. A token lying wholly inside the one found before it doesn't count
+/// though, so `dieciocho` is 18 and not also an `ocho`.
+#[derive(Debug, Clone, Copy)]
+pub struct DigitScanner<'v> {
+    vocabulary: &'v Vocabulary,
+    matching: Matching,
+}
+
+impl<'v> DigitScanner<'v> {
+    pub fn new(vocabulary: &'v Vocabulary, matching: Matching) -> Self {
+        DigitScanner {
+            vocabulary,
+            matching,
+        }
+    }
+
+    /// The token starting at byte `at`, if one does.
+    fn token_at(&self, line: &str, at: usize) -> Option<Token> {
+        let rest = &line.as_bytes()[at..];
+        let mut tokens = self
+            .vocabulary
+            .tokens
+            .iter()
+            .filter(|(token, _)| rest.starts_with(token.as_bytes()));
+        let (token, value) = match self.matching {
+            Matching::Longest => tokens.max_by_key(|(token, _)| token.len())?,
+            Matching::First => tokens.next()?,
+        };
+        Some(Token {
+            start: at,
+            end: at + token.len(),
+            value: *value,
+        })
+    }
+
+    fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token> + 'a {
+        let mut end = 0;
+        (0..line.len())
+            .filter_map(|at| self.token_at(line, at))
+            .filter(move |token| {
+                let inside = token.end <= end;
+                end = end.max(token.end);
+                !inside
+            })
+    }
+
+    /// The first and last token on the line, the same one twice if there's only one.
+    pub fn calibration(&self, line: &str) -> Option<Calibration> {
+        let mut tokens = self.tokens(line);
+        let first = tokens.next()?;
+        // Carried on from the front rather than searched for from the back
+        let (last, last_overlaps) = tokens.fold((first, false), |(previous, _), token| {
+            (token, token.start < previous.end)
+        });
+        Some(Calibration {
+            first,
+            last,
+            last_overlaps,
+        })
+    }
+
+    /// The values of the first and last digit on the line.
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let calibration = self.calibration(line)?;
+        Some((calibration.first.value, calibration.last.value))
+    }
+}
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn line_to_value(scanner: &DigitScanner, line: &str) -> Result<Calibration, InvalidLine> {
+    if line.trim().is_empty() {
+        return Err(InvalidLine::Empty);
+    }
+    scanner.calibration(line).ok_or(InvalidLine::NoDigit)
+}
+
+/// What `--report` says about a line: why it's skipped, or why its value may not be the one
+/// meant. Nothing for lines read as expected.
+pub fn line_note(scanner: &DigitScanner, line: &str) -> Option<String> {
+    let calibration = match line_to_value(scanner, line) {
+        Ok(calibration) => calibration,
+        Err(invalid) => return Some(format!("skipped, {invalid}")),
+    };
+    let suspicion = calibration.suspicion()?;
+    let token = |token: Token| format!("`{}` at {}", &line[token.start..token.end], token.start);
+    Some(format!(
+        "{} and {} read as {}, {suspicion}",
+        token(calibration.first),
+        token(calibration.last),
+        calibration.value()
+    ))
+}
+
+/// The sum of the calibration values of the lines that have one.
+pub fn calibration_sum(lines: &[&str], scanner: &DigitScanner) -> u64 {
+    lines
+        .iter()
+        .filter_map(|line| line_to_value(scanner, line).ok())
+        .map(|calibration| calibration.value())
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Error = Infallible;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> Self::Answer1 {
+        let vocabulary = Vocabulary::digits();
+        calibration_sum(lines, &DigitScanner::new(&vocabulary, Matching::default()))
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> Self::Answer2 {
+        let vocabulary = Vocabulary::english();
+        calibration_sum(lines, &DigitScanner::new(&vocabulary, Matching::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_and_last() {
+        let digits = Vocabulary::digits();
+        let digits = DigitScanner::new(&digits, Matching::Longest);
+        let english = Vocabulary::english();
+        let words = DigitScanner::new(&english, Matching::Longest);
+
+        assert_eq!(digits.first_and_last("treb7uchet"), Some((7, 7)));
+        assert_eq!(digits.first_and_last("two1nine"), Some((1, 1)));
+        assert_eq!(digits.first_and_last("eightwothree"), None);
+        assert_eq!(words.first_and_last("two1nine"), Some((2, 9)));
+        // Words sharing letters
+        assert_eq!(words.first_and_last("twone"), Some((2, 1)));
+        assert_eq!(words.first_and_last("eightwo"), Some((8, 2)));
+        assert_eq!(words.first_and_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(words.first_and_last("oneight"), Some((1, 8)));
+        assert_eq!(words.first_and_last("zero"), None);
+        assert_eq!(words.first_and_last("٣"), None);
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let spanish = Vocabulary::parse(include_str!("../vocabularies/spanish.txt")).unwrap();
+        let longest = DigitScanner::new(&spanish, Matching::Longest);
+        assert_eq!(longest.first_and_last("unodos"), Some((1, 2)));
+        assert_eq!(longest.first_and_last("x3ceroy"), Some((3, 0)));
+        // `diez` is also the start of `dieciocho`, `ocho` the end of it
+        assert_eq!(longest.first_and_last("diezdieciocho"), Some((10, 18)));
+        assert_eq!(longest.first_and_last("dosiete"), Some((2, 7)));
+
+        let english = Vocabulary::parse(include_str!("../vocabularies/english.txt")).unwrap();
+        let longest = DigitScanner::new(&english, Matching::Longest);
+        let first = DigitScanner::new(&english, Matching::First);
+        assert_eq!(longest.first_and_last("zeroseventeen"), Some((0, 17)));
+        assert_eq!(first.first_and_last("zeroseventeen"), Some((0, 7)));
+        assert_eq!(longest.first_and_last("tenineteen"), Some((10, 19)));
+        assert_eq!(first.first_and_last("tenineteen"), Some((10, 9)));
+        assert_eq!(line_to_value(&longest, "ten3").unwrap().value(), 103);
+    }
+
+    #[test]
+    fn test_vocabulary_errors() {
+        let error = Vocabulary::parse("# numbers\nuno 1\n\ndos\n").unwrap_err();
+        assert_eq!(error.kind, VocabularyErrorKind::MissingValue);
+        assert_eq!(
+            error.location,
+            Some(common::Location { line: 4, column: 1 })
+        );
+
+        let error = Vocabulary::parse("uno one\n").unwrap_err();
+        assert!(matches!(error.kind, VocabularyErrorKind::InvalidValue(_)));
+        assert_eq!(error.token, "one");
+        let error = Vocabulary::parse("uno 1 2\n").unwrap_err();
+        assert_eq!(error.kind, VocabularyErrorKind::TrailingText);
+        let error = Vocabulary::parse("uno 1\nuno 2\n").unwrap_err();
+        assert_eq!(error.kind, VocabularyErrorKind::DuplicateWord);
+        let error = Vocabulary::parse("7 8\n").unwrap_err();
+        assert_eq!(error.kind, VocabularyErrorKind::DuplicateWord);
+    }
+
+    #[test]
+    fn test_line_to_value() {
+        let english = Vocabulary::english();
+        let scanner = DigitScanner::new(&english, Matching::Longest);
+        let token = |start, end, value| Token { start, end, value };
+
+        let calibration = line_to_value(&scanner, "xtwone3four").unwrap();
+        assert_eq!(calibration.first, token(1, 4, 2));
+        assert_eq!(calibration.last, token(7, 11, 4));
+        assert_eq!(calibration.value(), 24);
+        assert_eq!(calibration.suspicion(), None);
+
+        let calibration = line_to_value(&scanner, "7twone").unwrap();
+        assert_eq!(calibration.last, token(3, 6, 1));
+        assert!(calibration.last_overlaps);
+        assert!(calibration.suspicion().is_some());
+
+        let calibration = line_to_value(&scanner, "treb7uchet").unwrap();
+        assert_eq!(calibration.first, calibration.last);
+        assert_eq!(calibration.suspicion(), None);
+
+        assert_eq!(line_to_value(&scanner, "abc"), Err(InvalidLine::NoDigit));
+        assert_eq!(line_to_value(&scanner, " "), Err(InvalidLine::Empty));
+    }
+
+    #[test]
+    fn test_line_note() {
+        let english = Vocabulary::english();
+        let scanner = DigitScanner::new(&english, Matching::Longest);
+
+        assert_eq!(line_note(&scanner, "treb7uchet"), None);
+        assert_eq!(line_note(&scanner, "xtwone3four"), None);
+        assert_eq!(
+            line_note(&scanner, "7twone").as_deref(),
+            Some(
+                "`7` at 0 and `one` at 3 read as 71, \
+                the last digit shares letters with the one before it"
+            )
+        );
+        assert_eq!(
+            line_note(&scanner, "abc").as_deref(),
+            Some("skipped, no digit on the line")
+        );
+        assert_eq!(
+            line_note(&scanner, "").as_deref(),
+            Some("skipped, the line is empty")
+        );
+    }
+
+    #[test]
+    fn test_samples() {
+        let lines = parse_input(include_str!("../sample.txt"));
+        assert_eq!(Day01::part1(&lines), 142);
+        assert_eq!(Day01::part2(&lines), 142);
+
+        // Part 1 skips the lines with digits only spelled out
+        let lines = parse_input(include_str!("../sample2.txt"));
+        assert_eq!(Day01::part1(&lines), 11 + 22 + 33 + 42 + 24 + 77);
+        assert_eq!(Day01::part2(&lines), 281);
+
+        let lines = parse_input("1abc2\nabc\n\nthree\n");
+        assert_eq!(Day01::part1(&lines), 12);
+        assert_eq!(Day01::part2(&lines), 12 + 33);
+    }
+}
@@ -1,50 +1,75 @@
-use regex::Regex;
-
-fn first_digit(pattern: &Regex, line: &str) -> i32 {
-    let res = pattern.captures(line).unwrap();
-    match &res[0] {
-        "one" => 1,
-        "two" => 2,
-        "three" => 3,
-        "four" => 4,
-        "five" => 5,
-        "six" => 6,
-        "seven" => 7,
-        "eight" => 8,
-        "nine" => 9,
-        _ => res[0].parse::<i32>().unwrap(),
-    }
-}
+use common::{
+    input::{exit_with_usage, from_args_with, take_flag, take_value},
+    output::Format,
+};
+use day01::{calibration_sum, line_note, Day01, DigitScanner, Matching, Vocabulary};
 
-fn last_digit(pattern: &Regex, line: &str) -> i32 {
-    let line_bacward = line.chars().rev().collect::<String>();
-    let res = pattern.captures(&line_bacward).unwrap();
-    match &res[0] {
-        "eno" => 1,
-        "owt" => 2,
-        "eerht" => 3,
-        "ruof" => 4,
-        "evif" => 5,
-        "xis" => 6,
-        "neves" => 7,
-        "thgie" => 8,
-        "enin" => 9,
-        _ => res[0].parse::<i32>().unwrap(),
-    }
+const OPTIONS: &str = "[--vocabulary FILE [--first-match]] [--report] ";
+
+fn read_vocabulary(path: &str) -> Vocabulary {
+    std::fs::read_to_string(path)
+        .map_err(|e| format!("couldn't read {path}: {e}"))
+        .and_then(|text| Vocabulary::parse(&text).map_err(|e| format!("{path}: {e}")))
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        })
 }
 
-fn line_to_value(first_pattern: &Regex, last_pattern: &Regex, line: &str) -> i32 {
-    let first_digit = first_digit(first_pattern, line);
-    let last_digit = last_digit(last_pattern, line);
-    10 * first_digit + last_digit
+/// Prints a part's sum, then the lines left out of it and those it may have read wrong.
+fn report(part: u8, lines: &[&str], scanner: &DigitScanner) {
+    println!("Day 01, part {part}: {}", calibration_sum(lines, scanner));
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(note) = line_note(scanner, line) {
+            println!("  line {}: {note}: `{line}`", i + 1);
+        }
+    }
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let lines = input.lines();
-    let first_pattern = Regex::new(r"\d|one|two|three|four|five|six|seven|eight|nine").unwrap();
-    let last_pattern = Regex::new(r"\d|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin").unwrap();
-    let values = lines.map(|line| line_to_value(&first_pattern, &last_pattern, line));
-    let sum: i32 = values.sum();
-    println!("{}", sum);
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let vocabulary =
+        take_value(&mut args, "--vocabulary").unwrap_or_else(|e| exit_with_usage(OPTIONS, &e));
+    let matching = if !take_flag(&mut args, "--first-match") {
+        Matching::Longest
+    } else if vocabulary.is_some() {
+        Matching::First
+    } else {
+        exit_with_usage(OPTIONS, "--first-match only applies to a --vocabulary");
+    };
+    let reporting = take_flag(&mut args, "--report");
+
+    let (input, format) = from_args_with(args, OPTIONS, env!("CARGO_MANIFEST_DIR"));
+    if vocabulary.is_none() && !reporting {
+        common::output::run_and_print::<Day01>(1, &input, format);
+        return;
+    }
+    if reporting && format == Format::Json {
+        exit_with_usage(
+            OPTIONS,
+            "--report is for people and can't be printed as JSON",
+        );
+    }
+
+    let digits = Vocabulary::digits();
+    // Only part 2 reads words, from the file if there's one
+    let words = vocabulary.map_or_else(Vocabulary::english, |path| read_vocabulary(&path));
+    let part1 = DigitScanner::new(&digits, Matching::default());
+    let part2 = DigitScanner::new(&words, matching);
+
+    if reporting {
+        let lines = input.lines().collect::<Vec<_>>();
+        report(1, &lines, &part1);
+        report(2, &lines, &part2);
+        return;
+    }
+
+    let run = common::run_parts(
+        || input.lines().collect::<Vec<_>>(),
+        &[
+            (1, &|lines: &Vec<&str>| calibration_sum(lines, &part1)),
+            (2, &|lines: &Vec<&str>| calibration_sum(lines, &part2)),
+        ],
+    );
+    common::output::print(1, &input, &run, format);
 }
@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day02::Day02;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day02>(0, None);
+    common::bench::solution::<Day02>(&mut c.benchmark_group("day02"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,411 @@
+//! Guessing the bag games were played with, from the sets drawn out of it.
+//!
+//! Each set is taken to be one handful drawn without replacement and put back before the next,
+//! so the chance of a set is multivariate hypergeometric. Bags are only ever guessed for the
+//! colors seen in the games.
+
+use crate::{minimum_cubes_required, Game, Set};
+
+/// The most cubes of a color the estimates weigh, unless the games show more than that.
+pub const SEARCH_CEILING: i32 = 100;
+
+/// How much likelier a game's sets must be under its own bag than under the shared one for
+/// [`inconsistent_games`] to report it, as a natural logarithm: e⁸ is about 3000 times.
+pub const INCONSISTENCY_THRESHOLD: f64 = 8.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub bag: Set,
+    /// The natural logarithm of the chance of drawing all the sets from the bag.
+    pub log_likelihood: f64,
+    /// The relative likelihood mass on this bag's count of each color, among the bags with any
+    /// other count of that color from 0 to the ceiling, multiplied over the colors as if they
+    /// were independent, though they share the handfuls.
+    ///
+    /// 1 when the sets rule out every other count, small when they can't tell counts apart. It's
+    /// no probability: the mass is spread over the counts up to the ceiling, so the same games
+    /// score lower with a higher ceiling.
+    pub confidence: f64,
+}
+
+/// A game whose sets are much likelier under a bag of its own than under the shared one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inconsistency {
+    pub id: i32,
+    pub own: Estimate,
+    /// How much likelier the sets are under the game's own bag, as a natural logarithm.
+    pub log_ratio: f64,
+}
+
+/// `ln(n!)` for every `n` up to some limit, so binomials are cheap.
+struct LnFactorials(Vec<f64>);
+
+impl LnFactorials {
+    fn up_to(n: usize) -> Self {
+        let mut table = Vec::with_capacity(n + 1);
+        table.push(0.0);
+        for i in 1..=n {
+            table.push(table[i - 1] + (i as f64).ln());
+        }
+        LnFactorials(table)
+    }
+
+    fn ln_choose(&self, n: i32, k: i32) -> f64 {
+        if k < 0 || k > n {
+            return f64::NEG_INFINITY;
+        }
+        let table = &self.0;
+        table[n as usize] - table[k as usize] - table[(n - k) as usize]
+    }
+}
+
+/// The chances of the games' sets under a bag, for one bag after another.
+struct Likelihood<'a> {
+    sets: Vec<&'a Set>,
+    ln_factorials: LnFactorials,
+}
+
+impl<'a> Likelihood<'a> {
+    /// Ready for any bag holding at most `max_cubes` cubes in all.
+    fn new(games: &'a [Game], max_cubes: i32) -> Self {
+        Likelihood {
+            sets: games.iter().flat_map(|game| &game.sets).collect(),
+            ln_factorials: LnFactorials::up_to(max_cubes.max(0) as usize),
+        }
+    }
+
+    fn ln_of(&self, bag: &Set) -> f64 {
+        let total = bag.cubes.values().sum::<i32>();
+        self.sets
+            .iter()
+            .map(|set| {
+                let drawn = set.cubes.values().sum::<i32>();
+                let ways = set
+                    .cubes
+                    .iter()
+                    .map(|(color, &count)| self.ln_factorials.ln_choose(bag.count(color), count));
+                let ways = ways.sum::<f64>();
+                // The bag can't give the set, and may hold fewer cubes than the handful
+                if ways == f64::NEG_INFINITY {
+                    return ways;
+                }
+                ways - self.ln_factorials.ln_choose(total, drawn)
+            })
+            .sum()
+    }
+
+    /// The log-likelihoods of `bag` with each of `counts` cubes of `color` instead.
+    fn profile(&self, bag: &Set, color: &str, counts: impl Iterator<Item = i32>) -> Vec<f64> {
+        let mut bag = bag.clone();
+        counts
+            .map(|count| {
+                bag.cubes.insert(color.to_owned(), count);
+                self.ln_of(&bag)
+            })
+            .collect()
+    }
+
+    fn estimate(&self, bag: Set, ceilings: &Set) -> Estimate {
+        let log_likelihood = self.ln_of(&bag);
+        let confidence = ceilings
+            .cubes
+            .iter()
+            .map(|(color, &ceiling)| {
+                let profile = self.profile(&bag, color, 0..=ceiling);
+                let peak = profile.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let total = profile.iter().map(|ln| (ln - peak).exp()).sum::<f64>();
+                (log_likelihood - peak).exp() / total
+            })
+            .product();
+        Estimate {
+            bag,
+            log_likelihood,
+            confidence,
+        }
+    }
+}
+
+/// The smallest bag each color could fill, which makes every game possible.
+fn minimum_bag(games: &[Game]) -> Set {
+    let mut minimum = Set::default();
+    for (color, count) in games
+        .iter()
+        .flat_map(|game| minimum_cubes_required(game).cubes)
+    {
+        let min_count = minimum.cubes.entry(color).or_insert(0);
+        *min_count = (*min_count).max(count);
+    }
+    minimum
+}
+
+/// The most cubes of each color to consider, and the most cubes in all.
+fn ceilings(minimum: &Set, ceiling: i32) -> (Set, i32) {
+    let ceilings = minimum
+        .cubes
+        .iter()
+        .map(|(color, &count)| (color.as_str(), count.max(ceiling)))
+        .collect::<Set>();
+    let total = ceilings.cubes.values().sum();
+    (ceilings, total)
+}
+
+/// The smallest bag all the games could have been played with, as [`minimum_cubes_required`]
+/// gives for one game, with its confidence up to `ceiling` cubes of a color.
+pub fn minimum_bound(games: &[Game], ceiling: i32) -> Estimate {
+    let minimum = minimum_bag(games);
+    let (ceilings, total) = ceilings(&minimum, ceiling);
+    Likelihood::new(games, total).estimate(minimum, &ceilings)
+}
+
+/// The likeliest of some bags, the first of those as likely.
+fn likeliest(bags: impl Iterator<Item = Option<(Set, f64)>>) -> Option<(Set, f64)> {
+    bags.flatten().fold(None, |best, (bag, ln)| match best {
+        Some((_, best_ln)) if best_ln >= ln => best,
+        _ => Some((bag, ln)),
+    })
+}
+
+/// A bag that makes the games' sets likely, with at most `ceiling` cubes of a color unless the
+/// games show more.
+///
+/// It's climbed to rather than searched for: bags are tried from the smallest total up, a cube
+/// at a time to whichever color it helps most, and each total is shared out by moving cubes
+/// between colors while that helps. That makes it a local optimum, no likelier bag being a cube
+/// added or moved away, but a likelier bag further off isn't ruled out. Totals the sets can't
+/// tell apart are left as small as possible.
+pub fn likely_bag(games: &[Game], ceiling: i32) -> Estimate {
+    let minimum = minimum_bag(games);
+    let (ceilings, total) = ceilings(&minimum, ceiling);
+    let likelihood = Likelihood::new(games, total);
+    let colors = minimum.cubes.keys().collect::<Vec<_>>();
+    let moved = |bag: &Set, from: Option<&String>, to: Option<&String>| {
+        let mut bag = bag.clone();
+        if let Some(from) = from {
+            *bag.cubes.get_mut(from)? -= 1;
+        }
+        if let Some(to) = to {
+            *bag.cubes.get_mut(to)? += 1;
+        }
+        let within = |color: &String| {
+            (minimum.count(color)..=ceilings.count(color)).contains(&bag.count(color))
+        };
+        colors.iter().all(|color| within(color)).then(|| {
+            let ln = likelihood.ln_of(&bag);
+            (bag, ln)
+        })
+    };
+    let pairs = colors
+        .iter()
+        .flat_map(|&from| colors.iter().map(move |&to| (from, to)))
+        .filter(|(from, to)| from != to)
+        .collect::<Vec<_>>();
+
+    let mut bag = minimum.clone();
+    let mut ln = likelihood.ln_of(&bag);
+    let mut best = (bag.clone(), ln);
+    loop {
+        while let Some((shared_out, shared_ln)) = likeliest(
+            pairs
+                .iter()
+                .map(|&(from, to)| moved(&bag, Some(from), Some(to))),
+        ) {
+            if shared_ln <= ln {
+                break;
+            }
+            (bag, ln) = (shared_out, shared_ln);
+        }
+        if ln > best.1 {
+            best = (bag.clone(), ln);
+        }
+
+        let added = colors.iter().map(|&color| moved(&bag, None, Some(color)));
+        match likeliest(added) {
+            Some(next) => (bag, ln) = next,
+            None => break,
+        }
+    }
+
+    likelihood.estimate(best.0, &ceilings)
+}
+
+/// The games much likelier under a bag of their own than under `shared`, most suspicious first.
+///
+/// `threshold` is how much likelier, as a natural logarithm; see [`INCONSISTENCY_THRESHOLD`].
+pub fn inconsistent_games(
+    games: &[Game],
+    shared: &Set,
+    ceiling: i32,
+    threshold: f64,
+) -> Vec<Inconsistency> {
+    let total = shared.cubes.values().sum::<i32>();
+    let mut inconsistencies = games
+        .iter()
+        .filter_map(|game| {
+            let game = std::slice::from_ref(game);
+            let own = likely_bag(game, ceiling);
+            let shared = Likelihood::new(game, total).ln_of(shared);
+            let log_ratio = own.log_likelihood - shared;
+            (log_ratio > threshold).then(|| Inconsistency {
+                id: game[0].id,
+                own,
+                log_ratio,
+            })
+        })
+        .collect::<Vec<_>>();
+    inconsistencies.sort_by(|a, b| b.log_ratio.total_cmp(&a.log_ratio));
+    inconsistencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_bag, parse_input};
+    use common::generate::Rng;
+
+    /// Games of handfuls drawn from `bag`, of up to `most` cubes each.
+    fn draw(rng: &mut Rng, bag: &Set, ids: std::ops::Range<i32>, most: usize) -> Vec<Game> {
+        let cubes = bag
+            .cubes
+            .iter()
+            .flat_map(|(color, &count)| std::iter::repeat_n(color.as_str(), count as usize))
+            .collect::<Vec<_>>();
+        ids.map(|id| {
+            let sets = (0..rng.between(1, 6))
+                .map(|_| {
+                    let mut cubes = cubes.clone();
+                    rng.shuffle(&mut cubes);
+                    let mut set = Set::default();
+                    let handful = rng.between(1, most.min(cubes.len()) as i64);
+                    for color in &cubes[..handful as usize] {
+                        *set.cubes.entry(color.to_string()).or_insert(0) += 1;
+                    }
+                    set
+                })
+                .collect();
+            Game { id, sets }
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_minimum_bound() {
+        let games =
+            parse_input("Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 1 blue; 2 red\n").unwrap();
+        let estimate = minimum_bound(&games, SEARCH_CEILING);
+        assert_eq!(estimate.bag, parse_bag("4 red, 2 green, 3 blue").unwrap());
+        assert!(estimate.log_likelihood < 0.0);
+        assert!((0.0..=1.0).contains(&estimate.confidence), "{estimate:?}");
+    }
+
+    #[test]
+    fn test_likely_bag() {
+        // Drawing every cube of the only color says nothing about whether there are more
+        let games = parse_input("Game 1: 3 red; 2 red").unwrap();
+        let estimate = likely_bag(&games, 10);
+        assert_eq!(estimate.bag, parse_bag("3 red").unwrap());
+        assert_eq!(estimate.log_likelihood, 0.0);
+        assert!((estimate.confidence - 1.0 / 8.0).abs() < 1e-9);
+
+        // Drawing the same whole bag again and again says it's all there is
+        let sets = |n| vec!["2 red, 1 blue"; n].join("; ");
+        let games = parse_input(&format!("Game 1: {}", sets(3))).unwrap();
+        let estimate = likely_bag(&games, 10);
+        assert_eq!(estimate.bag, parse_bag("2 red, 1 blue").unwrap());
+        assert_eq!(estimate.log_likelihood, 0.0);
+        let games = parse_input(&format!("Game 1: {}", sets(10))).unwrap();
+        let surer = likely_bag(&games, 10);
+        assert!(surer.confidence > 0.9);
+        assert!(surer.confidence > estimate.confidence);
+
+        let mut rng = Rng::new(2);
+        let bag = parse_bag("12 red, 13 green, 14 blue").unwrap();
+        let games = draw(&mut rng, &bag, 1..201, 20);
+        let minimum = minimum_bound(&games, SEARCH_CEILING);
+        let estimate = likely_bag(&games, SEARCH_CEILING);
+        assert!(minimum.bag.fits_in(&estimate.bag));
+        assert!(estimate.log_likelihood >= minimum.log_likelihood);
+        for (color, &count) in &bag.cubes {
+            let guess = estimate.bag.count(color);
+            assert!((guess - count).abs() <= 2, "{color}: {guess} for {count}");
+        }
+    }
+
+    #[test]
+    fn test_confidence_ceiling() {
+        // Nothing tells the counts apart, so the mass is shared by all up to the ceiling
+        let games = parse_input("Game 1: 3 red; 2 red").unwrap();
+        assert!((likely_bag(&games, 10).confidence - 1.0 / 8.0).abs() < 1e-9);
+        assert!((likely_bag(&games, 20).confidence - 1.0 / 18.0).abs() < 1e-9);
+
+        // The more the sets say, the less mass is left for a higher ceiling to take
+        let mut rng = Rng::new(4);
+        let bag = parse_bag("4 red, 5 green").unwrap();
+        let few = draw(&mut rng, &bag, 1..2, 3);
+        let (low, high) = (minimum_bound(&few, 10), minimum_bound(&few, 40));
+        assert!(
+            high.confidence < 0.9 * low.confidence,
+            "{high:?} against {low:?}"
+        );
+        let many = draw(&mut rng, &bag, 1..31, 6);
+        let (low, high) = (minimum_bound(&many, 10), minimum_bound(&many, 40));
+        assert!((high.confidence - low.confidence).abs() < 1e-6);
+    }
+
+    /// The likeliest bag, searched for among all with counts from the minimum up to `ceiling`.
+    fn likeliest_by_brute_force(games: &[Game], ceiling: i32) -> f64 {
+        let minimum = minimum_bag(games);
+        let (ceilings, total) = ceilings(&minimum, ceiling);
+        let likelihood = Likelihood::new(games, total);
+        let mut bags = vec![Set::default()];
+        for (color, &count) in &minimum.cubes {
+            bags = bags
+                .into_iter()
+                .flat_map(|bag| {
+                    (count..=ceilings.count(color)).map(move |count| {
+                        let mut bag = bag.clone();
+                        bag.cubes.insert(color.clone(), count);
+                        bag
+                    })
+                })
+                .collect();
+        }
+        let likelihoods = bags.iter().map(|bag| likelihood.ln_of(bag));
+        likelihoods.fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    #[test]
+    fn test_likely_bag_against_brute_force() {
+        let mut rng = Rng::new(5);
+        for _ in 0..20 {
+            let bag = (["red", "green", "blue"].iter())
+                .map(|&color| (color, rng.between(1, 5) as i32))
+                .collect::<Set>();
+            let games = draw(&mut rng, &bag, 1..3, 6);
+            let climbed = likely_bag(&games, 12);
+            let best = likeliest_by_brute_force(&games, 12);
+            assert!(
+                (climbed.log_likelihood - best).abs() < 1e-9,
+                "{} for {best} from {games:?}",
+                climbed.log_likelihood
+            );
+        }
+    }
+
+    #[test]
+    fn test_inconsistent_games() {
+        let mut rng = Rng::new(3);
+        let bag = parse_bag("12 red, 13 green, 14 blue").unwrap();
+        let mut games = draw(&mut rng, &bag, 1..41, 12);
+        let odd_bag = parse_bag("30 red, 1 green, 1 blue").unwrap();
+        games.extend(draw(&mut rng, &odd_bag, 41..42, 20));
+        games[40]
+            .sets
+            .extend(draw(&mut rng, &odd_bag, 0..3, 20)[0].sets.clone());
+
+        let shared = likely_bag(&games, SEARCH_CEILING);
+        let inconsistent =
+            inconsistent_games(&games, &shared.bag, SEARCH_CEILING, INCONSISTENCY_THRESHOLD);
+        assert_eq!(inconsistent.first().map(|game| game.id), Some(41));
+        assert!(inconsistent.len() <= 2, "{inconsistent:?}");
+    }
+}
@@ -0,0 +1,28 @@
+//! Made-up records of `size` games.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day02;
+
+impl Generator for Day02 {
+    const REAL_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for id in 1..=size {
+            let sets = (0..rng.between(1, 6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    colors[..rng.between(1, 3) as usize]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.between(1, 20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+            input.push_str(&format!("Game {id}: {}\n", sets.join("; ")));
+        }
+        input
+    }
+}
@@ -0,0 +1,306 @@
+use std::{collections::BTreeMap, fmt::Display, num::ParseIntError};
+
+use common::{Maybe, Solution};
+
+pub mod estimate;
+mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub id: i32,
+    pub sets: Vec<Set>,
+}
+
+/// How many cubes of each color, by color name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Set {
+    pub cubes: BTreeMap<String, i32>,
+}
+
+impl Set {
+    /// How many cubes of `color` there are, which is none if it isn't mentioned.
+    pub fn count(&self, color: &str) -> i32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// Whether every color shows no more cubes than in `other`.
+    pub fn fits_in(&self, other: &Set) -> bool {
+        self.cubes
+            .iter()
+            .all(|(color, &count)| count <= other.count(color))
+    }
+}
+
+/// Written the way the input does, e.g. `14 blue, 13 green, 12 red`.
+impl Display for Set {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cubes = self
+            .cubes
+            .iter()
+            .map(|(color, count)| format!("{count} {color}"));
+        write!(f, "{}", cubes.collect::<Vec<_>>().join(", "))
+    }
+}
+
+impl<'a> FromIterator<(&'a str, i32)> for Set {
+    fn from_iter<I: IntoIterator<Item = (&'a str, i32)>>(iter: I) -> Self {
+        let cubes = iter
+            .into_iter()
+            .map(|(color, count)| (color.to_owned(), count));
+        Set {
+            cubes: cubes.collect(),
+        }
+    }
+}
+
+/// The bag the puzzle asks about.
+pub const BAG: [(&str, i32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `Game <id>: <sets>`")]
+    MissingColon,
+    #[error("missing game id")]
+    MissingId,
+    #[error("missing cube color")]
+    MissingColor,
+    #[error("color given twice in a set")]
+    DuplicateColor,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+    #[error("cube counts must be between 0 and {MAX_CUBES}")]
+    CountOutOfRange,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+/// The most cubes of a color a set can show, which keeps the power of a set of the three colors
+/// of [`BAG`] well within range; powers of more colors can still overflow, see [`powers_sum`].
+pub const MAX_CUBES: i32 = 1000;
+
+fn parse_number(token: &str) -> Result<i32, ParseError> {
+    token
+        .parse()
+        .map_err(|e| ParseError::new(token, ParseErrorKind::InvalidNumber(e)))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    let lines = input.lines();
+    let games = lines.map(parse_game);
+    games.collect::<Result<_, _>>().map_err(|e| e.locate(input))
+}
+
+fn parse_game(input: &str) -> Result<Game, ParseError> {
+    // First split at the colon
+    let (before_colon, after_colon) = input
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(input, ParseErrorKind::MissingColon))?;
+
+    // Second word before the colon is the id
+    let id = before_colon
+        .split(' ')
+        .nth(1)
+        .ok_or_else(|| ParseError::new(before_colon, ParseErrorKind::MissingId))?;
+    let id = parse_number(id)?;
+
+    // Each set is separated by a semicolon
+    let sets = after_colon
+        .split(';')
+        .map(parse_set)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, sets })
+}
+
+fn parse_set(input: &str) -> Result<Set, ParseError> {
+    let mut set = Set::default();
+
+    let comma_splits = input.split(',');
+
+    for split in comma_splits {
+        let trimmed = split.trim();
+        let mut split = trimmed.split(' ');
+        let count_token = split.next().unwrap_or(trimmed);
+        let count = parse_number(count_token)?;
+        if !(0..=MAX_CUBES).contains(&count) {
+            return Err(ParseError::new(
+                count_token,
+                ParseErrorKind::CountOutOfRange,
+            ));
+        }
+        let color = split
+            .next()
+            .ok_or_else(|| ParseError::new(trimmed, ParseErrorKind::MissingColor))?;
+
+        if set.cubes.insert(color.to_owned(), count).is_some() {
+            return Err(ParseError::new(color, ParseErrorKind::DuplicateColor));
+        }
+    }
+
+    Ok(set)
+}
+
+/// Parses a bag written like a set, e.g. `12 red, 13 green, 14 blue`.
+pub fn parse_bag(input: &str) -> Result<Set, ParseError> {
+    parse_set(input).map_err(|e| e.locate(input))
+}
+
+pub fn minimum_cubes_required(game: &Game) -> Set {
+    let mut minimum = Set::default();
+    for (color, &count) in game.sets.iter().flat_map(|set| &set.cubes) {
+        let min_count = minimum.cubes.entry(color.clone()).or_insert(0);
+        *min_count = (*min_count).max(count);
+    }
+    minimum
+}
+
+/// Whether every set of the game could have been drawn from `bag`.
+pub fn is_possible(game: &Game, bag: &Set) -> bool {
+    game.sets.iter().all(|set| set.fits_in(bag))
+}
+
+/// The sum of the ids of the games that could have been played with `bag`.
+pub fn possible_ids_sum(games: &[Game], bag: &Set) -> i64 {
+    let possible = games.iter().filter(|game| is_possible(game, bag));
+    possible.map(|game| game.id as i64).sum()
+}
+
+/// The sum of the powers of the smallest bags each game could have been played with, a power
+/// being the product of the counts of the colors in `bag`, or `None` if that overflows an `i64`,
+/// as seven colors of [`MAX_CUBES`] cubes already do.
+pub fn powers_sum(games: &[Game], bag: &Set) -> Option<i64> {
+    let minimum_cubes = games.iter().map(minimum_cubes_required);
+
+    minimum_cubes
+        .map(|set| {
+            let mut counts = bag.cubes.keys().map(|color| set.count(color) as i64);
+            counts.try_fold(1i64, |power, count| power.checked_mul(count))
+        })
+        .try_fold(0i64, |total, power| total.checked_add(power?))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<Game>;
+    type Error = ParseError;
+    type Answer1 = i64;
+    type Answer2 = Maybe<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> Self::Answer1 {
+        possible_ids_sum(games, &BAG.into_iter().collect())
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> Self::Answer2 {
+        Maybe(powers_sum(games, &BAG.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+
+    #[test]
+    fn test_parse_game() {
+        let input = "Game 1: 1 green, 1 blue, 1 red; 3 green, 1 blue, 1 red; 4 green, 3 blue, 1 red; 4 green, 2 blue, 1 red; 3 blue, 3 green";
+        let game = parse_game(input).unwrap();
+
+        assert_eq!(game.id, 1);
+        assert_eq!(game.sets.len(), 5);
+        assert_eq!(game.sets[0].count("red"), 1);
+        assert_eq!(game.sets[0].count("green"), 1);
+        assert_eq!(game.sets[0].count("blue"), 1);
+        assert_eq!(game.sets[4].count("red"), 0);
+
+        let game = parse_game("Game 7: 2 purple, 1 red").unwrap();
+        assert_eq!(game.sets[0].count("purple"), 2);
+    }
+
+    #[test]
+    fn test_is_possible() {
+        let bag = parse_bag("12 red, 13 green, 14 blue").unwrap();
+        let possible = |line| is_possible(&parse_game(line).unwrap(), &bag);
+
+        assert!(possible(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+        ));
+        assert!(!possible(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green"
+        ));
+        assert!(possible("Game 4: 12 red, 13 green; 14 blue"));
+        // The bag has none of the other colors
+        assert!(!possible("Game 5: 1 purple"));
+        assert!(possible("Game 6: 0 purple"));
+    }
+
+    #[test]
+    fn test_powers_sum() {
+        let games = parse_input("Game 1: 2 red, 3 green; 4 purple\nGame 2: 1 red\n").unwrap();
+        assert_eq!(powers_sum(&games, &BAG.into_iter().collect()), Some(0));
+        assert_eq!(
+            powers_sum(&games, &parse_bag("1 red, 1 green").unwrap()),
+            Some(6)
+        );
+        assert_eq!(powers_sum(&games, &parse_bag("1 purple").unwrap()), Some(4));
+
+        // Seven colors of a thousand cubes make a power of 10²¹
+        let colors = ["a", "b", "c", "d", "e", "f", "g"];
+        let set = colors
+            .map(|color| format!("{MAX_CUBES} {color}"))
+            .join(", ");
+        let games = parse_input(&format!("Game 1: {set}\n")).unwrap();
+        let bag = |colors: &[&str]| {
+            let cubes = colors.iter().map(|color| format!("1 {color}"));
+            parse_bag(&cubes.collect::<Vec<_>>().join(", ")).unwrap()
+        };
+        assert_eq!(powers_sum(&games, &bag(&colors)), None);
+        assert_eq!(powers_sum(&games, &bag(&colors[..6])), Some(10i64.pow(18)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Game 1: 1 green, 1 blue\nGame 2: 3 red, 1 blue, 2 red\n";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::DuplicateColor);
+        assert_eq!(error.token, "red");
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 2,
+                column: 26
+            })
+        );
+
+        let error = parse_input("Game x: 1 red").unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::InvalidNumber(_)));
+        assert_eq!(error.location, Some(Location { line: 1, column: 6 }));
+
+        let error = parse_input("Game 1 1 red").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingColon);
+
+        let error = parse_input("Game 1: 2 red, -1 blue").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::CountOutOfRange);
+        assert_eq!(error.token, "-1");
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = common::generate::input::<Day02>(seed, Some(20));
+            let games = parse_input(&input).unwrap();
+            assert_eq!(games.len(), 20);
+            for game in &games {
+                let minimum = minimum_cubes_required(game);
+                assert!(game.sets.iter().all(|set| set.fits_in(&minimum)));
+                assert!(is_possible(game, &minimum));
+            }
+            Day02::part1(&games);
+            Day02::part2(&games);
+        }
+    }
+}
@@ -1,110 +1,83 @@
-#[derive(Debug, Clone)]
-pub struct Game {
-    id: i32,
-    sets: Vec<Set>,
-}
-
-#[derive(Debug, Clone)]
-pub struct Set {
-    reds: i32,
-    greens: i32,
-    blues: i32,
-}
-
-fn parse_input(input: &str) -> Vec<Game> {
-    let lines = input.lines();
-    let games = lines.map(parse_game);
-    games.collect()
-}
-
-fn parse_game(input: &str) -> Game {
-    // First split at the colon
-    let mut colon_split = input.split(':');
-    let before_colon = colon_split.next().unwrap();
-
-    // Second word before the colon is the id
-    let id: i32 = before_colon.split(' ').nth(1).unwrap().parse().unwrap();
-
-    let after_colon = colon_split.next().unwrap();
-    // Each set is separated by a semicolon
-    let sets = after_colon.split(';').map(parse_set).collect();
-
-    Game { id, sets }
-}
-
-fn parse_set(input: &str) -> Set {
-    let mut reds = 0;
-    let mut greens = 0;
-    let mut blues = 0;
-
-    let comma_splits = input.split(',');
-
-    for split in comma_splits {
-        let trimmed = split.trim();
-        let mut split = trimmed.split(' ');
-        let count: i32 = split.next().unwrap().parse().unwrap();
-        let color = split.next().unwrap();
-
-        match color {
-            "red" => reds = count,
-            "green" => greens = count,
-            "blue" => blues = count,
-            _ => panic!("Unknown color: {}", color),
-        }
-    }
-
-    Set {
-        reds,
-        greens,
-        blues,
-    }
-}
-
-fn minimum_cubes_required(game: &Game) -> Set {
-    let (min_reds, min_greens, min_blues) =
-        game.sets
-            .iter()
-            .fold((0, 0, 0), |(min_reds, min_greens, min_blues), set| {
-                (
-                    min_reds.max(set.reds),
-                    min_greens.max(set.greens),
-                    min_blues.max(set.blues),
-                )
-            });
-
-    Set {
-        reds: min_reds,
-        greens: min_greens,
-        blues: min_blues,
+use common::{
+    input::{exit_with_usage, from_args_with, take_flag, take_value},
+    output::Format,
+    Maybe,
+};
+use day02::{
+    estimate::{self, Estimate},
+    parse_bag, possible_ids_sum, powers_sum, Day02, Game,
+};
+
+const OPTIONS: &str = "[--bag CUBES | --report] ";
+
+/// Prints the bag the games were likely played with, and the games that don't fit it.
+fn report(games: &[Game]) {
+    let show = |what, estimate: &Estimate| {
+        let Estimate {
+            bag, confidence, ..
+        } = estimate;
+        format!("{what}: {bag} (confidence {confidence:.2})")
+    };
+    println!(
+        "Day 02, {}",
+        show(
+            "minimum bag",
+            &estimate::minimum_bound(games, estimate::SEARCH_CEILING)
+        )
+    );
+    let shared = estimate::likely_bag(games, estimate::SEARCH_CEILING);
+    println!("Day 02, {}", show("likely bag", &shared));
+
+    let inconsistent = estimate::inconsistent_games(
+        games,
+        &shared.bag,
+        estimate::SEARCH_CEILING,
+        estimate::INCONSISTENCY_THRESHOLD,
+    );
+    for game in inconsistent {
+        let own = show("own bag", &game.own);
+        let ratio = game.log_ratio;
+        println!(
+            "  game {}: e^{ratio:.1} times likelier with its {own}",
+            game.id
+        );
     }
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let games = parse_input(input);
-
-    let minimum_cubes = games.iter().map(minimum_cubes_required);
-
-    let sum_of_powers_of_minimum_cubes = minimum_cubes
-        .map(|set| set.reds * set.greens * set.blues)
-        .sum::<i32>();
-
-    println!("{}", sum_of_powers_of_minimum_cubes);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_game() {
-        let input = "Game 1: 1 green, 1 blue, 1 red; 3 green, 1 blue, 1 red; 4 green, 3 blue, 1 red; 4 green, 2 blue, 1 red; 3 blue, 3 green";
-        let game = parse_game(input);
-
-        assert_eq!(game.id, 1);
-        assert_eq!(game.sets.len(), 5);
-        assert_eq!(game.sets[0].reds, 1);
-        assert_eq!(game.sets[0].greens, 1);
-        assert_eq!(game.sets[0].blues, 1);
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let bag = take_value(&mut args, "--bag").unwrap_or_else(|e| exit_with_usage(OPTIONS, &e));
+    let reporting = take_flag(&mut args, "--report");
+
+    let (input, format) = from_args_with(args, OPTIONS, env!("CARGO_MANIFEST_DIR"));
+    if reporting {
+        if bag.is_some() {
+            exit_with_usage(OPTIONS, "--report guesses the bag and doesn't take one");
+        }
+        if format == Format::Json {
+            exit_with_usage(
+                OPTIONS,
+                "--report is for people and can't be printed as JSON",
+            );
+        }
+        report(&common::parse_or_exit::<Day02>(&input));
+        return;
     }
+    let Some(bag) = bag else {
+        common::output::run_and_print::<Day02>(2, &input, format);
+        return;
+    };
+    let bag =
+        parse_bag(&bag).unwrap_or_else(|e| exit_with_usage(OPTIONS, &format!("in --bag: {e}")));
+
+    let run = common::run_parts(
+        || common::parse_or_exit::<Day02>(&input),
+        &[
+            (1, &|games: &Vec<Game>| {
+                Maybe(Some(possible_ids_sum(games, &bag)))
+            }),
+            (2, &|games: &Vec<Game>| Maybe(powers_sum(games, &bag))),
+        ],
+    );
+    common::output::print(2, &input, &run, format);
 }
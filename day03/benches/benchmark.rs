@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day03::Day03;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day03>(0, None);
+    common::bench::solution::<Day03>(&mut c.benchmark_group("day03"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+//! Made-up engine schematics, `size` characters square.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day03;
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+
+impl Generator for Day03 {
+    const REAL_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut row = vec![b'.'; size];
+            let mut col = 0;
+            while col < size {
+                let digits = rng.between(1, 3) as usize;
+                // Numbers never touch the end of the row, where they would run into the next
+                if rng.chance(0.15) && col + digits < size {
+                    for cell in &mut row[col..col + digits] {
+                        *cell = b'1' + rng.below(9) as u8;
+                    }
+                    col += digits + 1;
+                } else {
+                    if rng.chance(0.06) {
+                        row[col] = if rng.chance(0.4) {
+                            b'*'
+                        } else {
+                            *rng.choose(SYMBOLS)
+                        };
+                    }
+                    col += 1;
+                }
+            }
+            input.push_str(std::str::from_utf8(&row).unwrap());
+            input.push('\n');
+        }
+        input
+    }
+}
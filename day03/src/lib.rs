@@ -0,0 +1,119 @@
+use std::collections::{HashMap, HashSet};
+
+use common::Solution;
+use grid::{Grid, Pos, ShapeError};
+
+mod generate;
+
+pub type Board = Grid<char>;
+
+/// The most digits a number can have, as in the real inputs, so that gear ratios stay in range.
+pub const MAX_DIGITS: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("numbers can have at most {MAX_DIGITS} digits")]
+    NumberTooLong,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_board(input: &str) -> Result<Board, ParseError> {
+    let mut digits = 0;
+    Grid::parse_with(input, |(_, col), c| {
+        digits = if c.is_ascii_digit() && col > 0 {
+            digits + 1
+        } else {
+            usize::from(c.is_ascii_digit())
+        };
+        if digits > MAX_DIGITS {
+            return Err(ParseErrorKind::NumberTooLong);
+        }
+        Ok(c)
+    })
+}
+
+fn find_part_numbers(board: &Board) -> Vec<i32> {
+    let mut part_numbers = Vec::new();
+    let mut current_number = 0;
+    let mut is_next_to_symbol = false;
+
+    for (row, cells) in board.rows().enumerate() {
+        // The extra `.` ends numbers at the end of the row
+        for (col, c) in cells.iter().chain(&['.']).enumerate() {
+            if c.is_ascii_digit() {
+                current_number = current_number * 10 + c.to_digit(10).unwrap() as i32;
+                is_next_to_symbol |= board
+                    .neighbours8((row, col))
+                    .any(|pos| !board[pos].is_ascii_digit() && board[pos] != '.')
+            } else {
+                if is_next_to_symbol {
+                    part_numbers.push(current_number);
+                }
+
+                current_number = 0;
+                is_next_to_symbol = false;
+            }
+        }
+    }
+    part_numbers
+}
+
+fn find_gears(board: &Board) -> Vec<(Pos, i32)> {
+    let mut possible_gears: HashMap<Pos, Vec<i32>> = HashMap::new(); // (row, col) -> Vec for possible gear ratio
+
+    let mut current_number = 0;
+    let mut all_neighbours: HashSet<Pos> = HashSet::new(); // Set of all neighbours of a full number
+
+    for (row, cells) in board.rows().enumerate() {
+        // The extra `.` ends numbers at the end of the row
+        for (col, c) in cells.iter().chain(&['.']).enumerate() {
+            if c.is_ascii_digit() {
+                current_number = current_number * 10 + c.to_digit(10).unwrap() as i32;
+                all_neighbours.extend(board.neighbours8((row, col)));
+            } else {
+                let gears = all_neighbours.iter().filter(|&&pos| board[pos] == '*');
+
+                for pos in gears {
+                    possible_gears
+                        .entry(*pos)
+                        .and_modify(|v| v.push(current_number))
+                        .or_insert_with(|| vec![current_number]);
+                }
+
+                current_number = 0;
+                all_neighbours.clear();
+            }
+        }
+    }
+
+    possible_gears
+        .into_iter()
+        .filter(|(_, v)| v.len() == 2)
+        .map(|(pos, v)| (pos, v[0] * v[1]))
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed<'a> = Board;
+    type Error = ParseError;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_board(input)
+    }
+
+    fn part1(board: &Self::Parsed<'_>) -> Self::Answer1 {
+        find_part_numbers(board).iter().sum()
+    }
+
+    fn part2(board: &Self::Parsed<'_>) -> Self::Answer2 {
+        let gears = find_gears(board);
+        gears.iter().map(|&(_, v)| v as i64).sum()
+    }
+}
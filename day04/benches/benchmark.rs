@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day04::Day04;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day04>(0, None);
+    common::bench::solution::<Day04>(&mut c.benchmark_group("day04"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
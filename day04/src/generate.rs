@@ -0,0 +1,46 @@
+//! Made-up piles of `size` scratchcards.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day04;
+
+impl Generator for Day04 {
+    const REAL_SIZE: usize = 220;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        // Cards only win copies of cards up to the end of their run, like in the real inputs,
+        // which keeps the number of copies from growing without bounds
+        let mut run_end = 0;
+        for id in 1..=size {
+            if id > run_end {
+                run_end = (id + rng.between(4, 14) as usize).min(size);
+            }
+            let matches = (rng.below(11) as usize).min(run_end - id);
+
+            let mut numbers = (1..100).collect::<Vec<_>>();
+            rng.shuffle(&mut numbers);
+            let winning = &numbers[..10];
+            let mut own = numbers[..matches]
+                .iter()
+                .chain(&numbers[10..35 - matches])
+                .copied()
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut own);
+
+            let format = |numbers: &[i32]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            input.push_str(&format!(
+                "Card {id:3}: {} | {}\n",
+                format(winning),
+                format(&own)
+            ));
+        }
+        input
+    }
+}
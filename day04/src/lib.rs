@@ -0,0 +1,190 @@
+use std::num::ParseIntError;
+
+use common::{Maybe, Solution};
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: i32,
+    pub winning_numbers: Vec<i32>,
+    pub own_numbers: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `Card <id>: <winning numbers> | <own numbers>`")]
+    MissingColon,
+    #[error("missing card id")]
+    MissingId,
+    #[error("missing `|` between winning and own numbers")]
+    MissingPipe,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_number(token: &str) -> Result<i32, ParseError> {
+    token
+        .parse()
+        .map_err(|e| ParseError::new(token, ParseErrorKind::InvalidNumber(e)))
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<i32>, ParseError> {
+    input.split_whitespace().map(parse_number).collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    input
+        .lines()
+        .map(parse_card)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(input))
+}
+
+fn parse_card(input: &str) -> Result<Card, ParseError> {
+    let (before_colon, after_colon) = input
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(input, ParseErrorKind::MissingColon))?;
+
+    // Second word before colon is the id
+    let id = before_colon
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| ParseError::new(before_colon, ParseErrorKind::MissingId))?;
+    let id = parse_number(id)?;
+
+    let (before_pipe, after_pipe) = after_colon
+        .split_once('|')
+        .ok_or_else(|| ParseError::new(after_colon, ParseErrorKind::MissingPipe))?;
+
+    // Numbers before pipe are the winning numbers
+    let winning_numbers = parse_numbers(before_pipe)?;
+
+    // Numbers after pipe are the own numbers
+    let own_numbers = parse_numbers(after_pipe)?;
+
+    Ok(Card {
+        id,
+        winning_numbers,
+        own_numbers,
+    })
+}
+
+fn matching_numbers_count(card: &Card) -> usize {
+    card.own_numbers
+        .iter()
+        .filter(|n| card.winning_numbers.contains(n))
+        .count()
+}
+
+/// The points for a card, unless they overflow.
+fn points_for_matching_numbers(count: usize) -> Option<i32> {
+    match count {
+        0 => Some(0),
+        n => 1i32.checked_shl(n as u32 - 1).filter(|&points| points > 0),
+    }
+}
+
+/// The number of cards won in total, unless it overflows.
+fn process_and_count_cards(cards: &[Card]) -> Option<usize> {
+    // (Card, Number of copies)
+    let mut card_vec = cards
+        .iter()
+        .map(|c| (c, 1))
+        .collect::<Vec<(&Card, usize)>>();
+
+    for i in 0..card_vec.len() {
+        let (left, right) = card_vec.split_at_mut(i + 1);
+        let (card, copies) = &left[i];
+
+        // Cards past the end of the table aren't won
+        for won in right.iter_mut().take(matching_numbers_count(card)) {
+            won.1 = won.1.checked_add(*copies)?;
+        }
+    }
+
+    card_vec
+        .iter()
+        .try_fold(0usize, |total, (_, copies)| total.checked_add(*copies))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed<'a> = Vec<Card>;
+    type Error = ParseError;
+    type Answer1 = Maybe<i32>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(cards: &Self::Parsed<'_>) -> Self::Answer1 {
+        cards
+            .iter()
+            .try_fold(0i32, |total, card| {
+                total.checked_add(points_for_matching_numbers(matching_numbers_count(card))?)
+            })
+            .into()
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> Self::Answer2 {
+        process_and_count_cards(cards).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+
+    #[test]
+    fn test_parse_card() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let card = parse_card(input).unwrap();
+        assert_eq!(card.id, 1);
+        assert_eq!(card.winning_numbers, vec![41, 48, 83, 86, 17]);
+        assert_eq!(card.own_numbers, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61\n").unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::InvalidNumber(_)));
+        assert_eq!(error.token, "x2");
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 2,
+                column: 12
+            })
+        );
+
+        let error = parse_card("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingPipe);
+    }
+
+    #[test]
+    fn test_past_the_end() {
+        let cards = parse_input("Card 1: 1 2 | 1 2\nCard 2: 3 | 4\n").unwrap();
+        assert_eq!(process_and_count_cards(&cards), Some(3));
+        assert_eq!(points_for_matching_numbers(31), Some(1 << 30));
+        assert_eq!(points_for_matching_numbers(32), None);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = common::generate::input::<Day04>(seed, Some(30));
+            let cards = parse_input(&input).unwrap();
+            assert_eq!(cards.len(), 30);
+            // Every card wins at least itself, and copies never run past the last card
+            assert!(process_and_count_cards(&cards).unwrap() >= cards.len());
+            assert_eq!(matching_numbers_count(cards.last().unwrap()), 0);
+            Day04::part1(&cards);
+        }
+    }
+}
@@ -3,16 +3,43 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use day05::*;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input_str = include_str!("../input.txt");
-    let input = parse_input(input_str);
+    let input_str = &common::generate::input::<Day05>(0, None);
+    common::bench::solution::<Day05>(&mut c.benchmark_group("day05"), input_str);
 
-    c.bench_function("lowest_location_backwards", |b| {
-        b.iter(|| lowest_location_backwards(black_box(&input)))
+    // Brute force doesn't finish on a real-sized input, so they're all compared on the sample
+    let sample = parse_input(include_str!("../sample.txt")).unwrap();
+    let mut group = c.benchmark_group("day05/lowest_location_sample");
+    group.bench_function("lowest_location_bf", |b| {
+        b.iter(|| lowest_location_bf(black_box(&sample)))
+    });
+    group.bench_function("lowest_location_backwards", |b| {
+        b.iter(|| lowest_location_backwards(black_box(&sample)))
+    });
+    #[cfg(feature = "parallel")]
+    group.bench_function("par_lowest_location_backwards", |b| {
+        b.iter(|| par_lowest_location_backwards(black_box(&sample)))
+    });
+    group.bench_function("lowest_location_intervals", |b| {
+        b.iter(|| lowest_location_intervals(black_box(&sample)))
     });
+    group.finish();
 
-    c.bench_function("lowest_location_intervals", |b| {
+    // Searching backwards takes seconds on an input of the real size, so both are compared on
+    // a smaller one
+    let input_str = &common::generate::input::<Day05>(1, Some(6));
+    let input = parse_input(input_str).unwrap();
+    let mut group = c.benchmark_group("day05/lowest_location");
+    group.bench_function("lowest_location_backwards", |b| {
+        b.iter(|| lowest_location_backwards(black_box(&input)))
+    });
+    #[cfg(feature = "parallel")]
+    group.bench_function("par_lowest_location_backwards", |b| {
+        b.iter(|| par_lowest_location_backwards(black_box(&input)))
+    });
+    group.bench_function("lowest_location_intervals", |b| {
         b.iter(|| lowest_location_intervals(black_box(&input)))
     });
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
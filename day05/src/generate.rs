@@ -0,0 +1,59 @@
+//! Made-up almanacs with `size` seed ranges, and numbers up to `size` digits long.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day05;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+impl Generator for Day05 {
+    const REAL_SIZE: usize = 10;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let universe = 10i64.pow(size.min(10) as u32);
+
+        let seeds = (0..size)
+            .map(|_| {
+                let start = rng.between(0, universe - 1);
+                let length = rng.between(1, (universe / (4 * size as i64)).max(1));
+                format!("{start} {}", length.min(universe - start))
+            })
+            .collect::<Vec<_>>();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for name in MAPS {
+            // Cut the numbers into segments, then shuffle the segments around
+            let segments = rng.between(size as i64, 4 * size as i64).min(universe) as usize;
+            let mut cuts = vec![0, universe];
+            while cuts.len() < segments + 1 {
+                let cut = rng.between(1, universe - 1);
+                if !cuts.contains(&cut) {
+                    cuts.push(cut);
+                }
+            }
+            cuts.sort_unstable();
+            let mut sources = cuts
+                .windows(2)
+                .map(|w| (w[0], w[1] - w[0]))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut sources);
+
+            input.push_str(&format!("\n{name} map:\n"));
+            let mut destination = 0;
+            for (source, length) in sources {
+                input.push_str(&format!("{destination} {source} {length}\n"));
+                destination += length;
+            }
+        }
+        input
+    }
+}
@@ -1,3 +1,9 @@
+use std::num::ParseIntError;
+
+use common::{interval::IntervalSet, Solution, Unsolved};
+
+mod generate;
+
 type SeedRange = std::ops::Range<i64>;
 
 #[derive(Debug)]
@@ -30,48 +36,27 @@ impl Map<'_> {
         unmapped
     }
 
-    fn map_range(&self, range: &SeedRange) -> Vec<SeedRange> {
-        let mut ranges_to_map = vec![range.clone()];
-        let mut mappings = vec![];
-
-        while let Some(range) = ranges_to_map.pop() {
-            let mut does_overlap = false;
-            for map_range in self.ranges.iter() {
-                let overlap_start = range.start.max(map_range.source_range_start);
-                let overlap_end = range
-                    .end
-                    .min(map_range.source_range_start + map_range.range_length);
-                if overlap_start < overlap_end {
-                    // There is an overlap
-                    mappings.push(
-                        (overlap_start - map_range.source_range_start
-                            + map_range.destination_range_start)
-                            ..(overlap_end - map_range.source_range_start
-                                + map_range.destination_range_start),
-                    );
-
-                    if overlap_start > range.start {
-                        // There is a range before the overlap
-                        // Since we could have other mappings, we need to check again
-                        ranges_to_map.push(range.start..overlap_start);
-                    }
-                    if range.end > overlap_end {
-                        // There is a range after the overlap
-                        // Since we could have other mappings, we need to check again
-                        ranges_to_map.push(overlap_end..range.end);
-                    }
-
-                    does_overlap = true;
-                    break;
-                }
-            }
-
-            if !does_overlap {
-                mappings.push(range.clone());
-            }
+    fn map_range(&self, seeds: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut unmapped = seeds.clone();
+        let mut mapped = IntervalSet::new();
+
+        for map_range in self.ranges.iter() {
+            let source_end = map_range.source_range_start + map_range.range_length;
+            let (below, rest) = unmapped.split_at(map_range.source_range_start);
+            let (inside, above) = rest.split_at(source_end);
+
+            let offset = map_range.destination_range_start - map_range.source_range_start;
+            mapped = mapped.union(
+                &inside
+                    .iter()
+                    .map(|r| r.start + offset..r.end + offset)
+                    .collect(),
+            );
+            unmapped = below.union(&above);
         }
 
-        mappings
+        // Values outside of every source range map to themselves
+        mapped.union(&unmapped)
     }
 }
 
@@ -101,7 +86,45 @@ impl MapRange {
     }
 }
 
-pub fn parse_input(input: &str) -> Input {
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `seeds: <start> <length> ...`")]
+    MissingSeeds,
+    #[error("seeds must come in `<start> <length>` pairs")]
+    UnpairedSeed,
+    #[error("expected `<name>:` header")]
+    MissingMapName,
+    #[error("expected `<destination> <source> <length>`")]
+    InvalidRange,
+    #[error("seed ranges can't be empty")]
+    EmptySeedRange,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+    #[error("numbers must be between 0 and {MAX_NUMBER}")]
+    NumberOutOfRange,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+/// The largest number in an input, far above the real ones, so that ends of ranges never
+/// overflow.
+pub const MAX_NUMBER: i64 = 1 << 40;
+
+fn parse_number(token: &str) -> Result<i64, ParseError> {
+    let number = token
+        .parse()
+        .map_err(|e| ParseError::new(token, ParseErrorKind::InvalidNumber(e)))?;
+    if !(0..=MAX_NUMBER).contains(&number) {
+        return Err(ParseError::new(token, ParseErrorKind::NumberOutOfRange));
+    }
+    Ok(number)
+}
+
+pub fn parse_input(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_blocks(input).map_err(|e| e.locate(input))
+}
+
+fn parse_blocks(input: &str) -> Result<Input<'_>, ParseError> {
     let mut parts = {
         let mut parts = input
             .lines()
@@ -119,49 +142,65 @@ pub fn parse_input(input: &str) -> Input {
         parts.0.into_iter()
     };
 
-    let seeds: Vec<SeedRange> = parts
+    let seeds_line = parts
         .next()
-        .unwrap()
-        .first()
-        .unwrap()
-        .split(':')
-        .nth(1)
-        .unwrap()
-        .split_whitespace()
-        .fold((vec![], None), |(mut acc, start), s| match start {
-            None => (acc, Some(s.parse::<i64>().unwrap())),
-            Some(start) => {
-                let size = s.parse::<i64>().unwrap();
-                acc.push(start..start + size);
-                (acc, None)
-            }
-        })
-        .0;
+        .and_then(|ls| ls.first().copied())
+        .ok_or_else(|| ParseError::whole_input(ParseErrorKind::MissingSeeds))?;
+    let seeds = parse_seeds(seeds_line)?;
 
     let maps = parts
-        .map(|ls| {
-            let name = ls.first().unwrap().split(':').next().unwrap();
-            let ranges = ls
-                .iter()
-                .skip(1)
-                .map(|l| {
-                    let mut parts = l.split_whitespace();
-                    let destination_range_start = parts.next().unwrap().parse::<i64>().unwrap();
-                    let source_range_start = parts.next().unwrap().parse::<i64>().unwrap();
-                    let range_length = parts.next().unwrap().parse::<i64>().unwrap();
-                    MapRange {
-                        destination_range_start,
-                        source_range_start,
-                        range_length,
-                    }
-                })
-                .collect::<Vec<_>>();
+        .filter(|ls| !ls.is_empty())
+        .map(|ls| parse_map(&ls))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Input { seeds, maps })
+}
+
+fn parse_seeds(line: &str) -> Result<Vec<SeedRange>, ParseError> {
+    let numbers = line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new(line, ParseErrorKind::MissingSeeds))?;
+
+    let mut seeds = vec![];
+    let mut tokens = numbers.split_whitespace();
+    while let Some(start) = tokens.next() {
+        let size = tokens
+            .next()
+            .ok_or_else(|| ParseError::new(start, ParseErrorKind::UnpairedSeed))?;
+        if parse_number(size)? == 0 {
+            return Err(ParseError::new(size, ParseErrorKind::EmptySeedRange));
+        }
+        let start = parse_number(start)?;
+        seeds.push(start..start + parse_number(size)?);
+    }
 
-            Map { name, ranges }
+    if seeds.is_empty() {
+        return Err(ParseError::new(line, ParseErrorKind::MissingSeeds));
+    }
+    Ok(seeds)
+}
+
+fn parse_map<'a>(lines: &[&'a str]) -> Result<Map<'a>, ParseError> {
+    let header = lines[0];
+    let name = header
+        .strip_suffix(':')
+        .ok_or_else(|| ParseError::new(header, ParseErrorKind::MissingMapName))?;
+
+    let ranges = lines[1..]
+        .iter()
+        .map(|l| {
+            let [destination, source, length] = l.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err(ParseError::new(l, ParseErrorKind::InvalidRange));
+            };
+            Ok(MapRange {
+                destination_range_start: parse_number(destination)?,
+                source_range_start: parse_number(source)?,
+                range_length: parse_number(length)?,
+            })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Input { seeds, maps }
+    Ok(Map { name, ranges })
 }
 
 // Very slow, DNF
@@ -175,25 +214,41 @@ pub fn lowest_location_bf(input: &Input) -> i64 {
         .unwrap()
 }
 
+/// Whether `location` maps back to one of the seeds.
+fn comes_from_seed(input: &Input, maps_in_reverse: &[&Map], location: i64) -> bool {
+    let mut current_value = location;
+
+    for map in maps_in_reverse.iter() {
+        current_value = map.unmap(current_value);
+    }
+
+    input.seeds.iter().any(|s| s.contains(&current_value))
+}
+
 // OK but
 pub fn lowest_location_backwards(input: &Input) -> i64 {
     let maps_in_reverse = input.maps.iter().rev().collect::<Vec<_>>();
 
     (0..)
-        .flat_map(|location| {
-            let mut current_value = location;
+        .find(|&location| comes_from_seed(input, &maps_in_reverse, location))
+        .unwrap()
+}
 
-            for map in maps_in_reverse.iter() {
-                current_value = map.unmap(current_value);
-            }
+/// [`lowest_location_backwards`], trying a block of locations at a time over all cores.
+#[cfg(feature = "parallel")]
+pub fn par_lowest_location_backwards(input: &Input) -> i64 {
+    use rayon::prelude::*;
 
-            if input.seeds.iter().any(|s| s.contains(&current_value)) {
-                Some(location)
-            } else {
-                None
-            }
+    // Enough work to keep every core busy, without searching too far past the answer
+    const BLOCK: i64 = 1 << 14;
+    let maps_in_reverse = input.maps.iter().rev().collect::<Vec<_>>();
+
+    (0..)
+        .find_map(|block| {
+            (block * BLOCK..(block + 1) * BLOCK)
+                .into_par_iter()
+                .find_first(|&location| comes_from_seed(input, &maps_in_reverse, location))
         })
-        .next()
         .unwrap()
 }
 
@@ -201,26 +256,44 @@ pub fn lowest_location_intervals(input: &Input) -> i64 {
     input
         .maps
         .iter()
-        .fold(input.seeds.clone(), |seeds, map| {
-            seeds
-                .iter()
-                .flat_map(|s| map.map_range(s))
-                .collect::<Vec<_>>()
+        .fold(input.seeds.iter().cloned().collect(), |seeds, map| {
+            map.map_range(&seeds)
         })
-        .iter()
-        .map(|r| r.start)
         .min()
         .unwrap()
 }
 
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed<'a> = Input<'a>;
+    type Error = ParseError;
+    type Answer1 = Unsolved;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(_input: &Self::Parsed<'_>) -> Self::Answer1 {
+        Unsolved
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> Self::Answer2 {
+        lowest_location_intervals(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::Location;
+    use proptest::{collection::vec, prelude::*};
 
     #[test]
     fn test_parse_input_2() {
         let input_str = include_str!("../sample.txt");
-        let input = parse_input(input_str);
+        let input = parse_input(input_str).unwrap();
         assert_eq!(input.seeds, vec![(79..79 + 14), (55..55 + 13)]);
         assert_eq!(input.maps.len(), 7);
         assert_eq!(input.maps[0].name, "seed-to-soil map");
@@ -279,7 +352,7 @@ mod tests {
     #[test]
     fn test_lowest_location_bf() {
         let input_str = include_str!("../sample.txt");
-        let input = parse_input(input_str);
+        let input = parse_input(input_str).unwrap();
         assert_eq!(lowest_location_bf(&input), 46);
     }
 
@@ -314,26 +387,125 @@ mod tests {
     #[test]
     fn test_lowest_location_backwards() {
         let input_str = include_str!("../sample.txt");
-        let input = parse_input(input_str);
+        let input = parse_input(input_str).unwrap();
         assert_eq!(lowest_location_backwards(&input), 46);
     }
 
     #[test]
     fn test_convert_range() {
         let input_str = include_str!("../sample.txt");
-        let input = parse_input(input_str);
+        let input = parse_input(input_str).unwrap();
         let map = &input.maps[0];
-        let mappings = map.map_range(&input.seeds[0]);
-        assert_eq!(mappings, vec![(81..95)]);
+        let mappings = map.map_range(&IntervalSet::from(input.seeds[0].clone()));
+        assert_eq!(mappings, IntervalSet::from(81..95));
     }
 
     #[test]
     fn test_lowest_location_intervals() {
         let input_str = include_str!("../sample.txt");
-        let input = parse_input(input_str);
+        let input = parse_input(input_str).unwrap();
 
         let res = lowest_location_intervals(&input);
 
         assert_eq!(res, 46);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("seeds: 79 14 55\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnpairedSeed);
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 1,
+                column: 14
+            })
+        );
+
+        let error = parse_input("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidRange);
+        assert_eq!(error.location, Some(Location { line: 4, column: 1 }));
+
+        let error = parse_input("seeds: 79 0\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::EmptySeedRange);
+        let error = parse_input("seeds:\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingSeeds);
+        let error = parse_input("seeds: 79 -14\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::NumberOutOfRange);
+        assert_eq!(error.token, "-14");
+    }
+
+    #[test]
+    fn test_generated() {
+        // Small enough for brute force to try every seed
+        for seed in 0..10 {
+            let input_str = common::generate::input::<Day05>(seed, Some(3));
+            let input = parse_input(&input_str).unwrap();
+            let expected = lowest_location_bf(&input);
+            assert_eq!(lowest_location_backwards(&input), expected);
+            assert_eq!(lowest_location_intervals(&input), expected);
+        }
+    }
+
+    /// A map that shuffles the segments between some cuts of `0..universe`, as real maps do, so
+    /// that every location comes from a single seed. Segments that stay put may be left out.
+    fn map_ranges(universe: i64) -> impl Strategy<Value = Vec<MapRange>> {
+        proptest::collection::btree_set(1..universe, 0..8)
+            .prop_flat_map(move |cuts| {
+                let bounds = [0].into_iter().chain(cuts).chain([universe]);
+                let bounds = bounds.collect::<Vec<_>>();
+                let segments = bounds
+                    .windows(2)
+                    .map(|w| (w[0], w[1] - w[0]))
+                    .collect::<Vec<_>>();
+                let listed = vec(any::<bool>(), segments.len());
+                (Just(segments).prop_shuffle(), listed)
+            })
+            .prop_map(|(sources, listed)| {
+                let mut destination = 0;
+                let mut ranges = vec![];
+                for ((source, length), listed) in sources.into_iter().zip(listed) {
+                    if listed || source != destination {
+                        ranges.push(MapRange {
+                            destination_range_start: destination,
+                            source_range_start: source,
+                            range_length: length,
+                        });
+                    }
+                    destination += length;
+                }
+                ranges
+            })
+    }
+
+    fn almanac() -> impl Strategy<Value = Input<'static>> {
+        (2..200i64).prop_flat_map(|universe| {
+            let seeds = vec((0..universe, 1..universe), 1..4).prop_map(move |seeds| {
+                seeds
+                    .into_iter()
+                    .map(|(start, length)| start..(start + length).min(universe))
+                    .collect()
+            });
+            let maps = vec(map_ranges(universe), 1..=7).prop_map(|maps| {
+                maps.into_iter()
+                    .map(|ranges| Map {
+                        name: "seed-to-soil map",
+                        ranges,
+                    })
+                    .collect()
+            });
+            (seeds, maps).prop_map(|(seeds, maps)| Input { seeds, maps })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_lowest_location_agree(input in almanac()) {
+            let expected = lowest_location_bf(&input);
+            prop_assert_eq!(lowest_location_backwards(&input), expected);
+            #[cfg(feature = "parallel")]
+            prop_assert_eq!(par_lowest_location_backwards(&input), expected);
+            prop_assert_eq!(lowest_location_intervals(&input), expected);
+        }
+    }
 }
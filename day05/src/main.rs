@@ -1,6 +1,5 @@
-fn main() {
-    let input_str = include_str!("../input.txt");
-    let input = day05::parse_input(input_str);
+use day05::Day05;
 
-    println!("{}", day05::lowest_location_intervals(&input));
+fn main() {
+    common::output::main::<Day05>(5, env!("CARGO_MANIFEST_DIR"));
 }
@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day06::*;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day06>(0, None);
+    common::bench::solution::<Day06>(&mut c.benchmark_group("day06"), input_str);
+
+    let race = join_races(&parse_input(input_str).unwrap()).unwrap();
+    let mut group = c.benchmark_group("day06/ways_to_beat_record");
+    group.bench_function("ways_to_beat_record", |b| {
+        b.iter(|| ways_to_beat_record(black_box(&race)).count())
+    });
+    group.bench_function("ways_to_beat_record_math", |b| {
+        b.iter(|| ways_to_beat_record_math(black_box(&race)).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+//! Made-up sheets of `size` races, up to four so the joined race of part 2 fits in an `i64`.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day06;
+
+impl Generator for Day06 {
+    const REAL_SIZE: usize = 4;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let races = (0..size.clamp(1, 4))
+            .map(|_| {
+                // A record below the best possible distance, so the race can be won
+                let time = rng.between(10, 99);
+                let record = rng.between(time, time * time / 4 - 1);
+                (time, record)
+            })
+            .collect::<Vec<_>>();
+
+        let times = races.iter().map(|(time, _)| format!("{time:>6}"));
+        let records = races.iter().map(|(_, record)| format!("{record:>6}"));
+        format!(
+            "Time:    {}\nDistance:{}\n",
+            times.collect::<String>(),
+            records.collect::<String>()
+        )
+    }
+}
@@ -0,0 +1,327 @@
+use std::num::ParseIntError;
+
+use common::{math::IntegerSqrt, Maybe, Solution};
+
+mod generate;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Race {
+    time: i64,            // milliseconds
+    record_distance: i64, // millimeters
+}
+
+impl Race {
+    const fn new(time: i64, record_distance: i64) -> Self {
+        Self {
+            time,
+            record_distance,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `Time:` line")]
+    MissingTimes,
+    #[error("expected `Distance:` line")]
+    MissingDistances,
+    #[error("{times} times but {distances} distances")]
+    MismatchedRaces { times: usize, distances: usize },
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+    #[error("times and distances can't be negative")]
+    NegativeNumber,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_numbers(
+    line: Option<&str>,
+    prefix: &str,
+    missing: ParseErrorKind,
+) -> Result<Vec<i64>, ParseError> {
+    let line = line.unwrap_or_default();
+    line.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(line, missing))?
+        .split_whitespace()
+        .map(|x| {
+            let number = x
+                .parse::<i64>()
+                .map_err(|e| ParseError::new(x, ParseErrorKind::InvalidNumber(e)))?;
+            if number < 0 {
+                return Err(ParseError::new(x, ParseErrorKind::NegativeNumber));
+            }
+            Ok(number)
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = input.lines();
+    let times = parse_numbers(lines.next(), "Time:", ParseErrorKind::MissingTimes);
+    let times = times.map_err(|e| e.locate(input))?;
+
+    let distance_line = lines.next();
+    let distances = parse_numbers(distance_line, "Distance:", ParseErrorKind::MissingDistances);
+    let distances = distances.map_err(|e| e.locate(input))?;
+
+    if times.len() != distances.len() {
+        let kind = ParseErrorKind::MismatchedRaces {
+            times: times.len(),
+            distances: distances.len(),
+        };
+        return Err(ParseError::new(distance_line.unwrap_or_default(), kind).locate(input));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(t, r)| Race::new(t, r))
+        .collect())
+}
+
+fn concat_digits(a: i64, b: i64) -> Option<i64> {
+    format!("{}{}", a, b).parse::<i64>().ok()
+}
+
+// The second part reads the numbers as if there were no spaces between them, unless that
+// overflows
+pub fn join_races(races: &[Race]) -> Option<Race> {
+    races.iter().try_fold(Race::new(0, 0), |joined, race| {
+        Some(Race::new(
+            concat_digits(joined.time, race.time)?,
+            concat_digits(joined.record_distance, race.record_distance)?,
+        ))
+    })
+}
+
+// Wider than the times, whose squares don't always fit
+fn compute_race_distance(race_time: i64, held_button_time: i64) -> i128 {
+    let boat_speed = held_button_time as i128;
+    let remaining_race_time = (race_time - held_button_time) as i128;
+    boat_speed * remaining_race_time
+}
+
+pub fn ways_to_beat_record(race: &Race) -> impl Iterator<Item = i64> + '_ {
+    (0..=race.time)
+        .map(|held_button_time| {
+            (
+                held_button_time,
+                compute_race_distance(race.time, held_button_time),
+            )
+        })
+        .filter(|(_, new_distance)| *new_distance > race.record_distance as i128)
+        .map(|(held_button_time, _)| held_button_time)
+}
+
+pub fn ways_to_beat_record_math(race: &Race) -> std::ops::Range<i64> {
+    // distance = speed * (time - speed), which beats the record strictly between the roots of
+    // speed^2 - time * speed + record = 0, i.e. (time ± sqrt(discriminant)) / 2
+    let (time, record) = (race.time as i128, race.record_distance as i128);
+    let discriminant = time * time - 4 * record;
+    let Some(root) = discriminant.floor_sqrt() else {
+        return 0..0;
+    };
+
+    // The floored root puts this just below the lower root, so only a few steps are left to
+    // the first speed that beats the record
+    let beats = |speed| compute_race_distance(race.time, speed) > record;
+    let mut speed_l = (time - root - 1).div_euclid(2).max(0) as i64;
+    while speed_l <= race.time / 2 && !beats(speed_l) {
+        speed_l += 1;
+    }
+    if speed_l > race.time / 2 {
+        return 0..0;
+    }
+
+    // The distances are symmetric around half the race time
+    speed_l..race.time - speed_l + 1
+}
+
+fn total_ways_to_beat_record_product<'a>(races: impl IntoIterator<Item = &'a Race>) -> Option<i64> {
+    races.into_iter().try_fold(1i64, |product, race| {
+        product.checked_mul(ways_to_beat_record_math(race).count() as i64)
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed<'a> = Vec<Race>;
+    type Error = ParseError;
+    type Answer1 = Maybe<i64>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(races: &Self::Parsed<'_>) -> Self::Answer1 {
+        total_ways_to_beat_record_product(races).into()
+    }
+
+    fn part2(races: &Self::Parsed<'_>) -> Self::Answer2 {
+        join_races(races)
+            .map(|race| ways_to_beat_record_math(&race).count())
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+    use proptest::prelude::*;
+
+    fn sample_input() -> &'static str {
+        "Time:      7  15   30\r\nDistance:  9  40  200\r\n"
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = sample_input();
+        let races = parse_input(input).unwrap();
+        assert_eq!(races[0], Race::new(7, 9));
+        assert_eq!(races[1], Race::new(15, 40));
+        assert_eq!(races[2], Race::new(30, 200));
+    }
+
+    #[test]
+    fn test_join_races() {
+        let input = sample_input();
+        let race = join_races(&parse_input(input).unwrap());
+        assert_eq!(race, Some(Race::new(71530, 940200)));
+
+        let races = [Race::new(1_000_000_000, 1), Race::new(1_000_000_000, 1)];
+        assert_eq!(join_races(&races), None);
+    }
+
+    #[test]
+    fn test_join_races_math() {
+        let input = sample_input();
+        let race = join_races(&parse_input(input).unwrap()).unwrap();
+        let ways_count = ways_to_beat_record_math(&race).count();
+        assert_eq!(ways_count, 71503);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("Time: 7 15\nDistance: 9\n").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::MismatchedRaces {
+                times: 2,
+                distances: 1
+            }
+        );
+        assert_eq!(error.location, Some(Location { line: 2, column: 1 }));
+
+        let error = parse_input("Time: 7 15\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingDistances);
+
+        let error = parse_input("Time: 7 15\nDistance: 9 -40\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::NegativeNumber);
+        assert_eq!(error.token, "-40");
+    }
+
+    #[test]
+    fn test_compute_race_time() {
+        let race_time = 7;
+        assert_eq!(compute_race_distance(race_time, 0), 0);
+        assert_eq!(compute_race_distance(race_time, 1), 6);
+        assert_eq!(compute_race_distance(race_time, 2), 10);
+        assert_eq!(compute_race_distance(race_time, 3), 12);
+        assert_eq!(compute_race_distance(race_time, 4), 12);
+        assert_eq!(compute_race_distance(race_time, 5), 10);
+        assert_eq!(compute_race_distance(race_time, 6), 6);
+        assert_eq!(compute_race_distance(race_time, 7), 0);
+    }
+
+    #[test]
+    fn test_ways_to_beat_record() {
+        let race = Race::new(7, 9);
+        let ways = ways_to_beat_record(&race).collect::<Vec<_>>();
+        assert_eq!(ways, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_ways_to_beat_record_math() {
+        let race = Race::new(7, 9);
+        let ways = ways_to_beat_record_math(&race).collect::<Vec<_>>();
+        assert_eq!(ways, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_total_ways_to_beat_record() {
+        let race = Race::new(7, 9);
+        let ways_count = ways_to_beat_record_math(&race).count();
+        assert_eq!(ways_count, 4);
+    }
+
+    #[test]
+    fn test_total_ways_to_beat_record_2() {
+        let race = Race::new(71530, 940200);
+        let ways_count = ways_to_beat_record_math(&race).count();
+        assert_eq!(ways_count, 71503);
+    }
+
+    #[test]
+    fn test_math() {
+        // [0, 1, 2, 3, 4] -> [0, 3, 4, 3, 0]
+        let r0 = Race::new(4, 0); // Can be beaten by [1, 2, 3] -> 3
+        let r1 = Race::new(4, 1); // Can be beaten by [1, 2, 3] -> 3
+        let r2 = Race::new(4, 2); // Can be beaten by [1, 2, 3] -> 3
+        let r3 = Race::new(4, 3); // Can be beaten by [2] -> 1
+        let r4 = Race::new(4, 4); // Can be beaten by [] -> 0
+        let ways = [r0, r1, r2, r3, r4]
+            .iter()
+            .map(ways_to_beat_record_math)
+            .map(Iterator::count)
+            .collect::<Vec<_>>();
+        assert_eq!(ways, vec![3, 3, 3, 1, 0]);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = common::generate::input::<Day06>(seed, Some(3));
+            let races = parse_input(&input).unwrap();
+            for race in races.iter().chain([&join_races(&races).unwrap()]) {
+                assert_eq!(
+                    ways_to_beat_record(race).count(),
+                    ways_to_beat_record_math(race).count()
+                );
+            }
+        }
+    }
+
+    /// A race, with a record anywhere from nothing to just past the furthest the boat can go.
+    fn race(max_time: i64) -> impl Strategy<Value = Race> {
+        (0..max_time).prop_flat_map(|time| {
+            (0..=time * time / 4 + 1).prop_map(move |record| Race::new(time, record))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_ways_to_beat_record_agree(race in race(2000)) {
+            let brute = ways_to_beat_record(&race).collect::<Vec<_>>();
+            prop_assert_eq!(ways_to_beat_record_math(&race).collect::<Vec<_>>(), brute);
+        }
+
+        #[test]
+        fn test_ways_to_beat_record_math_bounds(race in race(3_000_000_000)) {
+            // Too long a race to try every speed, but the range has to stop right at the record
+            let record = race.record_distance as i128;
+            let beats = |speed| compute_race_distance(race.time, speed) > record;
+            let ways = ways_to_beat_record_math(&race);
+            if ways.is_empty() {
+                prop_assert!(!beats(race.time / 2));
+            } else {
+                prop_assert!(beats(ways.start) && beats(ways.end - 1));
+                prop_assert!(ways.start == 0 || !beats(ways.start - 1));
+                prop_assert!(ways.end > race.time || !beats(ways.end));
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day07::Day07;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day07>(0, None);
+    common::bench::solution::<Day07>(&mut c.benchmark_group("day07"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+//! Made-up lists of `size` hands and their bets.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day07;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+impl Generator for Day07 {
+    const REAL_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            // Drawing from fewer distinct cards makes pairs and better hands more common
+            let mut pool = CARDS.to_vec();
+            rng.shuffle(&mut pool);
+            pool.truncate(rng.between(1, 5) as usize);
+            let hand = (0..5)
+                .map(|_| *rng.choose(&pool) as char)
+                .collect::<String>();
+            input.push_str(&format!("{hand} {}\n", rng.between(1, 1000)));
+        }
+        input
+    }
+}
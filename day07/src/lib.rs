@@ -0,0 +1,300 @@
+use std::num::ParseIntError;
+
+use common::{Maybe, Solution, Unsolved};
+
+mod generate;
+
+mod hand {
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+    pub enum HandError {
+        #[error("invalid card `{0}`")]
+        InvalidCard(char),
+        #[error("expected 5 cards, got {0}")]
+        WrongSize(usize),
+    }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+    pub enum Card {
+        J,
+        N(u8),
+        T,
+        Q,
+        K,
+        A,
+    }
+
+    impl TryFrom<char> for Card {
+        type Error = HandError;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                'A' => Ok(Card::A),
+                'K' => Ok(Card::K),
+                'Q' => Ok(Card::Q),
+                'J' => Ok(Card::J),
+                'T' => Ok(Card::T),
+                '2'..='9' => Ok(Card::N(value as u8 - b'0')),
+                _ => Err(HandError::InvalidCard(value)),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    enum Type {
+        HighCard,
+        OnePair,
+        TwoPair,
+        ThreeOfAKind,
+        FullHouse,
+        FourOfAKind,
+        FiveOfAKind,
+    }
+
+    #[derive(Debug)]
+    pub struct Hand {
+        cards: [Card; 5],
+    }
+
+    impl Hand {
+        pub fn new(cards: [Card; 5]) -> Self {
+            Self { cards }
+        }
+
+        fn hand_type(&self) -> Type {
+            let mut card_counts: HashMap<Card, usize> = HashMap::new();
+            let mut jokers = 0;
+            for card in self.cards.iter() {
+                if *card == Card::J {
+                    jokers += 1;
+                } else {
+                    *card_counts.entry(*card).or_default() += 1;
+                }
+            }
+
+            match card_counts.len() {
+                0 => Type::FiveOfAKind, // all jokers
+                1 => Type::FiveOfAKind,
+                2 => {
+                    if card_counts.values().any(|&count| count + jokers >= 4) {
+                        Type::FourOfAKind
+                    } else {
+                        Type::FullHouse
+                    }
+                }
+                3 => {
+                    if card_counts.values().any(|&count| count + jokers >= 3) {
+                        Type::ThreeOfAKind
+                    } else {
+                        Type::TwoPair
+                    }
+                }
+                4 => Type::OnePair,
+                5 => Type::HighCard,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    impl PartialEq for Hand {
+        fn eq(&self, other: &Self) -> bool {
+            self.cards
+                .iter()
+                .zip(other.cards.iter())
+                .all(|(a, b)| a == b)
+        }
+    }
+
+    impl Eq for Hand {}
+
+    impl Ord for Hand {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            match self.hand_type().cmp(&other.hand_type()) {
+                std::cmp::Ordering::Equal => self
+                    .cards
+                    .iter()
+                    .zip(other.cards.iter())
+                    .map(|(a, b)| a.cmp(b))
+                    .find(|&ordering| ordering != std::cmp::Ordering::Equal)
+                    .unwrap_or(std::cmp::Ordering::Equal),
+                ordering => ordering,
+            }
+        }
+    }
+
+    impl PartialOrd for Hand {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl TryFrom<&str> for Hand {
+        type Error = HandError;
+
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            let cards = value
+                .chars()
+                .map(Card::try_from)
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|cards: Vec<_>| HandError::WrongSize(cards.len()))?;
+            Ok(Self::new(cards))
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Hand::try_from("JKKK2").unwrap() < Hand::try_from("QJJQ2").unwrap());
+
+        assert_eq!(Hand::try_from("32T3K").unwrap().hand_type(), Type::OnePair);
+        assert_eq!(Hand::try_from("KK677").unwrap().hand_type(), Type::TwoPair);
+        assert_eq!(
+            Hand::try_from("T55J5").unwrap().hand_type(),
+            Type::FourOfAKind
+        );
+        assert_eq!(
+            Hand::try_from("KTJJT").unwrap().hand_type(),
+            Type::FourOfAKind
+        );
+        assert_eq!(
+            Hand::try_from("QQQJA").unwrap().hand_type(),
+            Type::FourOfAKind
+        );
+        assert_eq!(
+            Hand::try_from("KTJJT").unwrap().hand_type(),
+            Type::FourOfAKind
+        );
+    }
+
+    #[test]
+    fn test_invalid_hands() {
+        assert_eq!(Card::try_from('X'), Err(HandError::InvalidCard('X')));
+        assert_eq!(Hand::try_from("32T3X"), Err(HandError::InvalidCard('X')));
+        assert_eq!(Hand::try_from("32T3"), Err(HandError::WrongSize(4)));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    InvalidHand(#[from] hand::HandError),
+    #[error("missing bet")]
+    MissingBet,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_line(line: &str) -> Result<(hand::Hand, i32), ParseError> {
+    let (hand, bet) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(line, ParseErrorKind::MissingBet))?;
+    let hand = hand::Hand::try_from(hand).map_err(|e| ParseError::new(hand, e.into()))?;
+    let bet = bet
+        .trim()
+        .parse()
+        .map_err(|e| ParseError::new(bet, ParseErrorKind::InvalidNumber(e)))?;
+    Ok((hand, bet))
+}
+
+fn parse_input(input: &str) -> Result<Vec<(hand::Hand, i32)>, ParseError> {
+    input
+        .lines()
+        .map(parse_line)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(input))
+}
+
+/// Every hand's bet times its rank, summed, or `None` if that overflows an `i64`, which takes
+/// about 90 000 hands with the largest bets.
+fn total_winnings(hands_and_bets: &[(hand::Hand, i32)]) -> Option<i64> {
+    let mut hands_and_bets = hands_and_bets.iter().collect::<Vec<_>>();
+    hands_and_bets.sort_by(|(h_l, _), (h_r, _)| h_l.cmp(h_r));
+
+    let mut individual_winnings = hands_and_bets
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bet))| i64::try_from(i + 1).ok()?.checked_mul(*bet as i64));
+
+    individual_winnings.try_fold(0i64, |total, winnings| total.checked_add(winnings?))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Vec<(hand::Hand, i32)>;
+    type Error = ParseError;
+    type Answer1 = Unsolved;
+    type Answer2 = Maybe<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(_hands_and_bets: &Self::Parsed<'_>) -> Self::Answer1 {
+        Unsolved
+    }
+
+    fn part2(hands_and_bets: &Self::Parsed<'_>) -> Self::Answer2 {
+        Maybe(total_winnings(hands_and_bets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+
+    fn sample_input() -> &'static str {
+        include_str!("../sample.txt")
+    }
+
+    #[test]
+    fn test_parsing() {
+        let input = sample_input();
+        parse_input(input).unwrap();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("32T3K 765\nT55X5 684\n").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::InvalidHand(hand::HandError::InvalidCard('X'))
+        );
+        assert_eq!(error.location, Some(Location { line: 2, column: 1 }));
+
+        let error = parse_input("32T3K\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingBet);
+    }
+
+    #[test]
+    fn test_total_winnings() {
+        let input = sample_input();
+        let hands_and_bets = parse_input(input).unwrap();
+        let winnings = total_winnings(&hands_and_bets);
+        assert_eq!(winnings, Some(5905));
+
+        let input = format!("AAAAA {}\n", i32::MAX).repeat(100_000);
+        let hands_and_bets = parse_input(&input).unwrap();
+        assert_eq!(total_winnings(&hands_and_bets), None);
+        assert!(total_winnings(&hands_and_bets[..80_000]).is_some());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = common::generate::input::<Day07>(seed, Some(50));
+            let hands_and_bets = parse_input(&input).unwrap();
+            assert_eq!(hands_and_bets.len(), 50);
+            // Every hand is worth at least its bet
+            let bets = hands_and_bets
+                .iter()
+                .map(|&(_, bet)| bet as i64)
+                .sum::<i64>();
+            assert!(total_winnings(&hands_and_bets).unwrap() >= bets);
+        }
+    }
+}
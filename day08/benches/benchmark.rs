@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day08::Day08;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day08>(0, None);
+    common::bench::solution::<Day08>(&mut c.benchmark_group("day08"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+//! Made-up maps for `size` ghosts, up to eight so the answer to part 2 fits in a `u64`.
+
+use std::collections::HashSet;
+
+use common::generate::{Generator, Rng};
+
+use crate::Day08;
+
+const PRIMES: [usize; 21] = [
+    11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Picks an unused node name ending in `last`.
+fn name(rng: &mut Rng, used: &mut HashSet<String>, last: char) -> String {
+    loop {
+        let letter = |rng: &mut Rng| char::from(b'A' + rng.below(26) as u8);
+        let name = format!("{}{}{last}", letter(rng), letter(rng));
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// A name ending in neither `A` nor `Z`, for nodes along the way.
+fn middle_name(rng: &mut Rng, used: &mut HashSet<String>) -> String {
+    let last = char::from(b'B' + rng.below(24) as u8);
+    name(rng, used, last)
+}
+
+impl Generator for Day08 {
+    const REAL_SIZE: usize = 6;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut primes = PRIMES.to_vec();
+        rng.shuffle(&mut primes);
+        primes.truncate(size.clamp(1, 8));
+
+        let mut used = HashSet::from(["AAA".to_owned(), "ZZZ".to_owned()]);
+        let mut nodes = vec![];
+        for (ghost, &period) in primes.iter().enumerate() {
+            // Every ghost walks around rings of `period` nodes, on a Z node at the end of each
+            // lap. The first ghost has one ring from AAA to ZZZ, the others two, and switch
+            // between them as the directions say.
+            let lanes = if ghost == 0 { 1 } else { 2 };
+            let rings = (0..lanes)
+                .map(|_| {
+                    (0..period)
+                        .map(|i| match (ghost, i + 1 == period) {
+                            (0, true) => "ZZZ".to_owned(),
+                            (_, true) => name(rng, &mut used, 'Z'),
+                            _ => middle_name(rng, &mut used),
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let next = |i: usize| {
+                let left = rings[0][i % period].clone();
+                let right = rings[lanes - 1][i % period].clone();
+                (left, right)
+            };
+
+            let start = match ghost {
+                0 => "AAA".to_owned(),
+                _ => name(rng, &mut used, 'A'),
+            };
+            nodes.push((start, next(0)));
+            for ring in &rings {
+                for (i, name) in ring.iter().enumerate() {
+                    nodes.push((name.clone(), next(i + 1)));
+                }
+            }
+        }
+        rng.shuffle(&mut nodes);
+
+        let length = rng.between(50, 300);
+        let mut input = (0..length)
+            .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+            .collect::<String>();
+        input.push_str("\n\n");
+        for (name, (left, right)) in nodes {
+            input.push_str(&format!("{name} = ({left}, {right})\n"));
+        }
+        input
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    cycle::{self, Cycle},
+    geometry::Turn,
+    math::Congruence,
+    Maybe, Solution,
+};
+use nom::{
+    bytes::complete::{tag, take},
+    character::complete::{char, line_ending},
+    combinator::{map, opt},
+    multi::separated_list1,
+    IResult,
+};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected a line of `L`/`R` directions")]
+    InvalidDirection,
+    #[error("expected a blank line after the directions")]
+    MissingBlankLine,
+    #[error("expected `AAA = (BBB, CCC)`")]
+    InvalidNode,
+    #[error("reference to undefined node")]
+    UnknownNode,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+/// Turns a nom failure into an error pointing at the line where parsing stopped.
+fn nom_error(kind: ParseErrorKind) -> impl Fn(nom::Err<nom::error::Error<&str>>) -> ParseError {
+    move |e| {
+        let rest = match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e.input,
+            nom::Err::Incomplete(_) => "",
+        };
+        ParseError::new(rest.lines().next().unwrap_or(rest), kind.clone())
+    }
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Turn> {
+    let parse_left = map(char('L'), |_| Turn::Left);
+    let parse_right = map(char('R'), |_| Turn::Right);
+    nom::branch::alt((parse_left, parse_right))(input)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<'a> {
+    name: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+fn parse_node(input: &str) -> IResult<&str, Node<'_>> {
+    let (input, name) = take(3usize)(input)?;
+    let (input, _) = tag(" = (")(input)?;
+    let (input, left) = take(3usize)(input)?;
+    let (input, _) = tag(", ")(input)?;
+    let (input, right) = take(3usize)(input)?;
+    let (input, _) = tag(")")(input)?;
+
+    Ok((input, Node { name, left, right }))
+}
+
+#[derive(Debug)]
+pub struct Map<'a> {
+    directions: Vec<Turn>,
+    nodes: HashMap<&'a str, Node<'a>>,
+}
+
+impl<'a> TryFrom<&'a str> for Map<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        parse_map(value).map_err(|e| e.locate(value))
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map<'_>, ParseError> {
+    let (input, directions) = nom::multi::many1(parse_direction)(input)
+        .map_err(nom_error(ParseErrorKind::InvalidDirection))?;
+    let (input, _) = nom::multi::count(line_ending, 2)(input)
+        .map_err(nom_error(ParseErrorKind::MissingBlankLine))?;
+    let (input, nodes) = separated_list1(line_ending, parse_node)(input)
+        .map_err(nom_error(ParseErrorKind::InvalidNode))?;
+    let (input, _) = opt(line_ending)(input).map_err(nom_error(ParseErrorKind::InvalidNode))?;
+    if !input.is_empty() {
+        let line = input.lines().next().unwrap_or(input);
+        return Err(ParseError::new(line, ParseErrorKind::InvalidNode));
+    }
+
+    let names = nodes.iter().map(|node| node.name).collect::<HashSet<_>>();
+    if let Some(unknown) = nodes
+        .iter()
+        .flat_map(|node| [node.left, node.right])
+        .find(|next| !names.contains(next))
+    {
+        return Err(ParseError::new(unknown, ParseErrorKind::UnknownNode));
+    }
+
+    let nodes = nodes
+        .into_iter()
+        .map(|node| (node.name, node))
+        .collect::<HashMap<_, _>>();
+
+    Ok(Map { directions, nodes })
+}
+
+/// Where a ghost is in its walk: the node it's on and the next instruction to follow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Ghost<'a> {
+    node: &'a str,
+    instruction: usize,
+}
+
+impl<'a> Map<'a> {
+    fn step(&self, ghost: &mut Ghost<'a>) {
+        let node = &self.nodes[ghost.node];
+        ghost.node = match self.directions[ghost.instruction] {
+            Turn::Left => node.left,
+            Turn::Right => node.right,
+        };
+        ghost.instruction = (ghost.instruction + 1) % self.directions.len();
+    }
+}
+
+/// The steps from AAA to ZZZ, unless there's no AAA or it never gets to ZZZ.
+fn steps_to_reach_zzz(map: &Map) -> Option<usize> {
+    if !map.nodes.contains_key("AAA") {
+        return None;
+    }
+
+    let mut ghost = Ghost {
+        node: "AAA",
+        instruction: 0,
+    };
+    // After as many steps as there are ghost states, the walk has gone round in circles
+    (0..=map.nodes.len() * map.directions.len()).find(|_| {
+        let found = ghost.node == "ZZZ";
+        map.step(&mut ghost);
+        found
+    })
+}
+
+fn ends_in_z(node: &str) -> bool {
+    node.as_bytes()[2] == b'Z'
+}
+
+/// When a ghost is on a node ending in Z, which repeats once the ghost's walk does.
+#[derive(Debug)]
+struct Schedule {
+    cycle: Cycle,
+    /// Steps, up to when the walk first repeats, after which the ghost is on a Z node.
+    hits: Vec<usize>,
+}
+
+impl Schedule {
+    fn new(map: &Map, start: &str) -> Self {
+        let start = Ghost {
+            node: start,
+            instruction: 0,
+        };
+        // There are only so many nodes and instructions, so the walk always repeats
+        let cycle = cycle::brent(&start, |ghost| map.step(ghost), usize::MAX).unwrap();
+
+        let mut ghost = start;
+        let mut hits = vec![];
+        for steps in 0..cycle.prefix + cycle.period {
+            if ends_in_z(ghost.node) {
+                hits.push(steps);
+            }
+            map.step(&mut ghost);
+        }
+        Schedule { cycle, hits }
+    }
+
+    fn is_hit(&self, steps: usize) -> bool {
+        self.hits.binary_search(&self.cycle.reduce(steps)).is_ok()
+    }
+
+    /// The remainders modulo the period of the steps that hit once the walk repeats.
+    fn repeating_hits(&self) -> impl Iterator<Item = usize> + '_ {
+        let Cycle { prefix, period } = self.cycle;
+        self.hits
+            .iter()
+            .filter(move |&&steps| steps >= prefix)
+            .map(move |steps| steps % period)
+    }
+}
+
+fn steps_to_reach_all_xxz(map: &Map) -> Option<usize> {
+    let schedules = map
+        .nodes
+        .keys()
+        .filter(|name| name.as_bytes()[2] == b'A')
+        .map(|name| Schedule::new(map, name))
+        .collect::<Vec<_>>();
+
+    // Before every walk repeats, just try every step
+    let all_repeating = schedules.iter().map(|s| s.cycle.prefix).max()?.max(1);
+    if let Some(steps) = (1..all_repeating).find(|&steps| schedules.iter().all(|s| s.is_hit(steps)))
+    {
+        return Some(steps);
+    }
+
+    // Afterwards, the steps that hit for every ghost are those that hit one of each ghost's
+    // remainders at the same time
+    let mut congruences = vec![Congruence::new(0, 1)];
+    for schedule in &schedules {
+        let period = schedule.cycle.period as i64;
+        congruences = congruences
+            .iter()
+            .flat_map(|congruence| {
+                schedule
+                    .repeating_hits()
+                    .filter_map(move |r| congruence.combine(&Congruence::new(r as i64, period)))
+            })
+            .collect();
+    }
+
+    congruences
+        .into_iter()
+        .filter_map(|congruence| congruence.first_at_least(all_repeating as i64))
+        .map(|steps| steps as usize)
+        .min()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed<'a> = Map<'a>;
+    type Error = ParseError;
+    type Answer1 = Maybe<usize>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Self::Answer1 {
+        steps_to_reach_zzz(map).into()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> Self::Answer2 {
+        // The ghosts might never be all on Z nodes at once
+        steps_to_reach_all_xxz(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+
+    fn sample() -> &'static str {
+        include_str!("../sample.txt")
+    }
+
+    fn sample2() -> &'static str {
+        include_str!("../sample2.txt")
+    }
+
+    fn sample3() -> &'static str {
+        include_str!("../sample3.txt")
+    }
+
+    #[test]
+    fn test_parse() {
+        let map: Map = sample().try_into().unwrap();
+        assert_eq!(map.directions.len(), 2);
+        assert_eq!(map.nodes.len(), 7);
+
+        assert_eq!(map.nodes["AAA"].left, "BBB");
+        assert_eq!(map.nodes["AAA"].right, "CCC");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Map::try_from("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnknownNode);
+        assert_eq!(error.token, "ZZZ");
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 4,
+                column: 13
+            })
+        );
+
+        let error = Map::try_from("LR\n\nAAA = (AAA, AAA)\nBBB = AAA\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidNode);
+        assert_eq!(error.location, Some(Location { line: 4, column: 1 }));
+
+        let error = Map::try_from("LX\n\nAAA = (AAA, AAA)\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingBlankLine);
+        assert_eq!(error.location, Some(Location { line: 1, column: 2 }));
+    }
+
+    #[test]
+    fn test_num_steps() {
+        let map_1 = sample().try_into().unwrap();
+        let steps_1 = steps_to_reach_zzz(&map_1);
+        assert_eq!(steps_1, Some(2));
+
+        let map2 = sample2().try_into().unwrap();
+        let steps_2 = steps_to_reach_zzz(&map2);
+        assert_eq!(steps_2, Some(6));
+
+        let map = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(steps_to_reach_zzz(&map.try_into().unwrap()), None);
+        let map = "L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(steps_to_reach_zzz(&map.try_into().unwrap()), None);
+    }
+
+    #[test]
+    fn test_num_steps_all_xxz() {
+        let map = sample3().try_into().unwrap();
+        let steps = steps_to_reach_all_xxz(&map);
+        assert_eq!(steps, Some(6));
+    }
+
+    #[test]
+    fn test_num_steps_all_xxz_out_of_phase() {
+        // XXA is on XXZ every third step starting at 2, and YYA on YYZ every odd step
+        let map = "L\n\nXXA = (XXB, XXB)\nXXB = (XXZ, XXZ)\nXXZ = (XXA, XXA)\n\
+                   YYA = (YYZ, YYZ)\nYYZ = (YYA, YYA)\n";
+        let map = map.try_into().unwrap();
+        assert_eq!(steps_to_reach_all_xxz(&map), Some(5));
+
+        // AAA is only on CCZ after even steps
+        let map = "L\n\nAAA = (BBB, BBB)\nBBB = (CCZ, CCZ)\nCCZ = (DDD, DDD)\n\
+                   DDD = (CCZ, CCZ)\nYYA = (YYZ, YYZ)\nYYZ = (YYA, YYA)\n";
+        let map = map.try_into().unwrap();
+        assert_eq!(steps_to_reach_all_xxz(&map), None);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            // With a single ghost, both parts walk from AAA to ZZZ
+            let input = common::generate::input::<Day08>(seed, Some(1));
+            let map = Day08::parse(&input).unwrap();
+            assert_eq!(steps_to_reach_all_xxz(&map), steps_to_reach_zzz(&map));
+
+            let input = common::generate::input::<Day08>(seed, Some(3));
+            let map = Day08::parse(&input).unwrap();
+            assert!(steps_to_reach_all_xxz(&map).is_some());
+        }
+    }
+}
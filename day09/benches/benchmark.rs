@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day09::Day09;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day09>(0, None);
+    common::bench::solution::<Day09>(&mut c.benchmark_group("day09"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
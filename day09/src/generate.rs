@@ -0,0 +1,38 @@
+//! Made-up reports of `size` histories, each a polynomial sampled at 21 points.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day09;
+
+const LENGTH: usize = 21;
+
+impl Generator for Day09 {
+    const REAL_SIZE: usize = 200;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            // Pick the first value of every row of differences, down to a constant row, and add
+            // them back up. Small enough values keep even the sums of the answers in an `i32`.
+            let degree = rng.between(0, 8) as usize;
+            let mut firsts = (0..=degree)
+                .map(|_| rng.between(-5, 5))
+                .collect::<Vec<i64>>();
+            let mut history = vec![];
+            for _ in 0..LENGTH {
+                history.push(firsts[0]);
+                for k in 0..degree {
+                    firsts[k] += firsts[k + 1];
+                }
+            }
+
+            let line = history
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>();
+            input.push_str(&line.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -0,0 +1,145 @@
+use std::num::ParseIntError;
+
+use common::{math::NewtonPolynomial, Solution};
+
+mod generate;
+
+/// Sequences are at most this long, which keeps the divided differences within an `i128` and the
+/// extrapolated values within an `i64`.
+pub const MAX_LENGTH: usize = 24;
+
+/// Extends the sequence one value to each side, taking it to be a polynomial in the index.
+fn extrapolate_sequence(sequence: &[i32]) -> (i64, i64) {
+    let points = sequence
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| (i as i128, value as i128))
+        .collect::<Vec<_>>();
+    let polynomial = NewtonPolynomial::fit(&points);
+
+    // Integer values at consecutive integers make for integer values at every integer
+    let value_at = |x: i128| polynomial.evaluate(x).to_integer() as i64;
+    (value_at(-1), value_at(sequence.len() as i128))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("empty sequence")]
+    EmptySequence,
+    #[error("sequence longer than {MAX_LENGTH} values")]
+    SequenceTooLong,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_sequence(line: &str) -> Result<Vec<i32>, ParseError> {
+    let sequence = line
+        .split_whitespace()
+        .map(|word| {
+            word.parse::<i32>()
+                .map_err(|e| ParseError::new(word, ParseErrorKind::InvalidNumber(e)))
+        })
+        .collect::<Result<Vec<i32>, _>>()?;
+
+    if sequence.is_empty() {
+        return Err(ParseError::new(line, ParseErrorKind::EmptySequence));
+    }
+    if sequence.len() > MAX_LENGTH {
+        return Err(ParseError::new(line, ParseErrorKind::SequenceTooLong));
+    }
+    Ok(sequence)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .lines()
+        .map(parse_sequence)
+        .collect::<Result<Vec<Vec<i32>>, _>>()
+        .map_err(|e| e.locate(input))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<Vec<i32>>;
+    type Error = ParseError;
+    // Sums of values that can take up most of an `i64` each
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(sequences: &Self::Parsed<'_>) -> Self::Answer1 {
+        sequences
+            .iter()
+            .map(|sequence| extrapolate_sequence(sequence).1 as i128)
+            .sum()
+    }
+
+    fn part2(sequences: &Self::Parsed<'_>) -> Self::Answer2 {
+        sequences
+            .iter()
+            .map(|sequence| extrapolate_sequence(sequence).0 as i128)
+            .sum()
+    }
+}
+
+#[test]
+fn test_next_element_in_sequence() {
+    let sequence = [0, 3, 6, 9, 12, 15];
+    assert_eq!(extrapolate_sequence(&sequence).1, 18);
+}
+
+#[test]
+fn test_previous_element_in_sequence() {
+    let sequence = [10, 13, 16, 21, 30, 45];
+    assert_eq!(extrapolate_sequence(&sequence).0, 5);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("0 3 6\n1 3 x 10\n").unwrap_err();
+    assert!(matches!(error.kind, ParseErrorKind::InvalidNumber(_)));
+    assert_eq!(error.token, "x");
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 5 })
+    );
+
+    let error = parse_input("0 3 6\n\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::EmptySequence);
+
+    let error = parse_input(&"1 ".repeat(MAX_LENGTH + 1)).unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::SequenceTooLong);
+}
+
+#[test]
+fn test_extreme_values() {
+    // Alternating extremes make for the largest differences, far past an `i32`
+    let sequence = (0..MAX_LENGTH)
+        .map(|i| if i % 2 == 0 { i32::MAX } else { i32::MIN })
+        .collect::<Vec<_>>();
+    let (previous, next) = extrapolate_sequence(&sequence);
+    assert_eq!(previous, 36_028_794_863_091_712);
+    assert_eq!(next, -36_028_794_863_091_713);
+}
+
+#[test]
+fn test_generated() {
+    // The sequences are of low degree, so either end follows from the rest
+    for seed in 0..10 {
+        let input = common::generate::input::<Day09>(seed, Some(20));
+        for sequence in parse_input(&input).unwrap() {
+            let last = sequence.len() - 1;
+            assert_eq!(
+                extrapolate_sequence(&sequence[..last]).1,
+                sequence[last] as i64
+            );
+            assert_eq!(extrapolate_sequence(&sequence[1..]).0, sequence[0] as i64);
+        }
+    }
+}
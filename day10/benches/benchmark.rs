@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day10::Day10;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day10>(0, None);
+    common::bench::solution::<Day10>(&mut c.benchmark_group("day10"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+//! Made-up pipe maps, `size` tiles square, with a single loop through the start tile.
+
+use common::{
+    generate::{self, Generator, Rng},
+    geometry::Direction,
+};
+use grid::Grid;
+
+use crate::Day10;
+
+/// The pipe joining the two directions.
+fn pipe(a: Direction, b: Direction) -> char {
+    use Direction::*;
+    match (a.min(b), a.max(b)) {
+        (North, South) => '|',
+        (East, West) => '-',
+        (North, East) => 'L',
+        (North, West) => 'J',
+        (South, West) => '7',
+        (East, South) => 'F',
+        _ => unreachable!("{a:?} and {b:?} don't make a pipe"),
+    }
+}
+
+fn connects(tile: char, direction: Direction) -> bool {
+    use Direction::*;
+    matches!(
+        (tile, direction),
+        ('|' | 'L' | 'J', North)
+            | ('|' | '7' | 'F', South)
+            | ('-' | 'L' | 'F', East)
+            | ('-' | 'J' | '7', West)
+    )
+}
+
+impl Generator for Day10 {
+    const REAL_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(4);
+        let mut grid = Grid::from_fn(size, size, |_| {
+            *rng.choose(&['.', '.', '|', '-', 'L', 'J', '7', 'F'])
+        });
+
+        // Spread the loop out to every other tile, so it encloses the tiles in between
+        let blocks = (size / 4).max(1);
+        let most = (blocks * blocks * 2 / 3).max(blocks);
+        let nodes = rng.between(blocks as i64, most as i64) as usize;
+        let corners = generate::tree_loop(rng, blocks, blocks, nodes);
+        let cells = corners
+            .iter()
+            .zip(corners.iter().cycle().skip(1))
+            .flat_map(|(&from, &to)| [from * 2, from * 2 + (to - from)])
+            .collect::<Vec<_>>();
+        let direction = |from: usize, to: usize| {
+            let offset = cells[to % cells.len()] - cells[from % cells.len()];
+            *Direction::ALL
+                .iter()
+                .find(|direction| direction.offset() == offset)
+                .unwrap()
+        };
+        for i in 0..cells.len() {
+            let (before, after) = (i + cells.len() - 1, i + 1);
+            grid[cells[i].to_pos().unwrap()] = pipe(direction(i, before), direction(i, after));
+        }
+
+        // Leave the start with the only two pipes that lead into it
+        let start = rng.index(cells.len());
+        let start_pos = cells[start].to_pos().unwrap();
+        grid[start_pos] = 'S';
+        for direction in Direction::ALL {
+            let on_loop = [start + 1, start + cells.len() - 1]
+                .iter()
+                .any(|&i| cells[start].step(direction) == cells[i % cells.len()]);
+            if let Some(pos) = grid.step(start_pos, direction) {
+                if !on_loop && connects(grid[pos], direction.reverse()) {
+                    grid[pos] = '.';
+                }
+            }
+        }
+
+        grid.to_string()
+    }
+}
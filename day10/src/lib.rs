@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+
+use common::{
+    geometry::{Coord, Direction},
+    Maybe, Solution,
+};
+use grid::{Grid, ShapeError};
+
+mod generate;
+
+#[derive(PartialEq, Eq, Copy, Clone)]
+enum Tile {
+    NorthSouth, // |
+    EastWest,   // -
+    NorthEast,  // L
+    NorthWest,  // J
+    SouthWest,  // 7
+    SouthEast,  // F
+    Ground,     // .
+    Start,      // S
+}
+
+impl core::fmt::Debug for Tile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Tile::NorthSouth => write!(f, "|"),
+            Tile::EastWest => write!(f, "-"),
+            Tile::NorthEast => write!(f, "L"),
+            Tile::NorthWest => write!(f, "J"),
+            Tile::SouthWest => write!(f, "7"),
+            Tile::SouthEast => write!(f, "F"),
+            Tile::Ground => write!(f, "."),
+            Tile::Start => write!(f, "S"),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Tile::NorthSouth),
+            '-' => Ok(Tile::EastWest),
+            'L' => Ok(Tile::NorthEast),
+            'J' => Ok(Tile::NorthWest),
+            '7' => Ok(Tile::SouthWest),
+            'F' => Ok(Tile::SouthEast),
+            '.' => Ok(Tile::Ground),
+            'S' => Ok(Tile::Start),
+            _ => Err(ParseErrorKind::InvalidTile(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("invalid tile")]
+    InvalidTile(char),
+    #[error("missing start tile `S`")]
+    MissingStart,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl Map {
+    fn get_tile(&self, coord: Coord) -> Option<&Tile> {
+        self.tiles.at(coord)
+    }
+
+    fn find_start(&self) -> Option<Coord> {
+        let start = self.tiles.position(|tile| *tile == Tile::Start)?;
+        Some(Coord::from_pos(start))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let map = Map {
+        tiles: Grid::parse(input)?,
+    };
+    if map.find_start().is_none() {
+        return Err(ParseError::whole_input(ParseErrorKind::MissingStart));
+    }
+    Ok(map)
+}
+
+fn compute_edges(map: &Map, coord: Coord) -> Vec<Coord> {
+    let mut edges: Vec<Coord> = vec![];
+    let tile = map.get_tile(coord).unwrap();
+
+    let northern_coord = coord.step(Direction::North);
+    let northern: Option<(Tile, Coord)> =
+        map.get_tile(northern_coord).map(|t| (*t, northern_coord));
+
+    let southern_coord = coord.step(Direction::South);
+    let southern: Option<(Tile, Coord)> =
+        map.get_tile(southern_coord).map(|t| (*t, southern_coord));
+
+    let eastern_coord = coord.step(Direction::East);
+    let eastern: Option<(Tile, Coord)> = map.get_tile(eastern_coord).map(|t| (*t, eastern_coord));
+
+    let western_coord = coord.step(Direction::West);
+    let western: Option<(Tile, Coord)> = map.get_tile(western_coord).map(|t| (*t, western_coord));
+
+    match tile {
+        Tile::NorthSouth => {
+            [northern, southern]
+                .iter()
+                .flatten()
+                .for_each(|t| edges.push(t.1));
+        }
+        Tile::EastWest => {
+            [eastern, western]
+                .iter()
+                .flatten()
+                .for_each(|t| edges.push(t.1));
+        }
+        Tile::NorthEast => {
+            [northern, eastern]
+                .iter()
+                .flatten()
+                .for_each(|t| edges.push(t.1));
+        }
+        Tile::NorthWest => {
+            [northern, western]
+                .iter()
+                .flatten()
+                .for_each(|t| edges.push(t.1));
+        }
+        Tile::SouthWest => {
+            [southern, western]
+                .iter()
+                .flatten()
+                .for_each(|t| edges.push(t.1));
+        }
+        Tile::SouthEast => {
+            [southern, eastern]
+                .iter()
+                .flatten()
+                .for_each(|t| edges.push(t.1));
+        }
+        Tile::Start => {
+            [
+                match northern {
+                    Some((Tile::NorthSouth | Tile::SouthWest | Tile::SouthEast, _)) => northern,
+                    _ => None,
+                },
+                match southern {
+                    Some((Tile::NorthSouth | Tile::NorthWest | Tile::NorthEast, _)) => southern,
+                    _ => None,
+                },
+                match eastern {
+                    Some((Tile::EastWest | Tile::NorthWest | Tile::SouthWest, _)) => eastern,
+                    _ => None,
+                },
+                match western {
+                    Some((Tile::EastWest | Tile::NorthEast | Tile::SouthEast, _)) => western,
+                    _ => None,
+                },
+            ]
+            .iter()
+            .flatten()
+            .for_each(|t| edges.push(t.1));
+        }
+        Tile::Ground => {}
+    }
+
+    edges
+}
+
+fn compute_adjacency_matrix(map: &Map) -> Grid<Vec<Coord>> {
+    Grid::from_fn(map.tiles.height(), map.tiles.width(), |pos| {
+        compute_edges(map, Coord::from_pos(pos))
+    })
+}
+
+fn compute_longest_distance(map: &Map, adjacency_matrix: &Grid<Vec<Coord>>) -> usize {
+    let start = map.find_start().unwrap();
+    let search = search::bfs(start, |coord| {
+        adjacency_matrix[coord.to_pos().unwrap()].iter().copied()
+    });
+
+    *search.distances.values().max().unwrap()
+}
+
+type MainLoop = HashMap<Coord, i64>;
+
+/// Follows the pipes from the start back to it, unless they lead somewhere else, e.g. into a pipe
+/// that doesn't connect back.
+fn compute_main_loop(map: &Map, adjacency_matrix: &Grid<Vec<Coord>>) -> Option<MainLoop> {
+    let start = map.find_start().unwrap();
+    let edges = |coord: Coord| &adjacency_matrix[coord.to_pos().unwrap()];
+
+    let mut main_loop = vec![start];
+    let mut next_node = *edges(start).first()?;
+    while next_node != start {
+        let current_node = next_node;
+        let previous_node = *main_loop.last().unwrap();
+        if !edges(current_node).contains(&previous_node) {
+            return None;
+        }
+        main_loop.push(current_node);
+        next_node = *edges(current_node)
+            .iter()
+            .find(|&&node| node != previous_node)?;
+    }
+    // Only the start can connect to more than two pipes, so the walk can't go round any other loop
+    if main_loop.len() < 3 || !edges(start).contains(main_loop.last().unwrap()) {
+        return None;
+    }
+
+    let mut main_loop_map = HashMap::new();
+
+    // Compute direction (+1 if south, -1 if north)
+    for i in 0..main_loop.len() {
+        let previous_node = main_loop[(i + main_loop.len() - 1) % main_loop.len()];
+        let current_node = main_loop[i];
+        let next_node = main_loop[(i + 1) % main_loop.len()];
+
+        let direction = next_node.row - previous_node.row;
+
+        main_loop_map.insert(current_node, direction);
+    }
+
+    Some(main_loop_map)
+}
+
+fn compute_tiles_contained_by_main_loop(map: &Map, main_loop: &MainLoop) -> Vec<Coord> {
+    let mut tiles_contained_by_main_loop = vec![];
+
+    for y in 0..map.tiles.height() {
+        let mut crosses = 0;
+        for x in 0..map.tiles.width() {
+            let coord = Coord::from_pos((y, x));
+            if let Some(dy) = main_loop.get(&coord).copied() {
+                crosses += dy;
+            } else if crosses != 0 {
+                tiles_contained_by_main_loop.push(coord);
+            }
+        }
+    }
+
+    tiles_contained_by_main_loop
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Map;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Self::Answer1 {
+        let adjacency_matrix = compute_adjacency_matrix(map);
+        compute_longest_distance(map, &adjacency_matrix)
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> Self::Answer2 {
+        let adjacency_matrix = compute_adjacency_matrix(map);
+        let main_loop = compute_main_loop(map, &adjacency_matrix);
+        main_loop
+            .map(|main_loop| compute_tiles_contained_by_main_loop(map, &main_loop).len())
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_compute_adjacency_matrix() {
+        let input = ".....\r\n.S-7.\r\n.|.|.\r\n.L-J.\r\n.....\r\n";
+        let map = parse_input(input).unwrap();
+        let adjacency_matrix = compute_adjacency_matrix(&map);
+
+        assert_eq!(adjacency_matrix[(0, 0)], vec![]);
+        assert_eq!(
+            adjacency_matrix[(1, 1)]
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>(),
+            HashSet::from([Coord::new(1, 2), Coord::new(2, 1)])
+        );
+        assert_eq!(
+            adjacency_matrix[(1, 3)]
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>(),
+            HashSet::from([Coord::new(1, 2), Coord::new(2, 3)])
+        );
+    }
+
+    #[test]
+    fn test_compute_longest_distance() {
+        let input = "..F7.\r\n.FJ|.\r\nSJ.L7\r\n|F--J\r\nLJ...\r\n";
+        let map = parse_input(input).unwrap();
+        let adjacency_matrix = compute_adjacency_matrix(&map);
+        let longest_distance = compute_longest_distance(&map, &adjacency_matrix);
+        assert_eq!(longest_distance, 8);
+    }
+
+    #[test]
+    fn test_compute_main_loop() {
+        let input = ".....\r\n\
+        .S-7.\r\n\
+        .|.|.\r\n\
+        .L-J.\r\n\
+        .....\r\n";
+        let map = parse_input(input).unwrap();
+        let adjacency_matrix = compute_adjacency_matrix(&map);
+        let main_loop = compute_main_loop(&map, &adjacency_matrix).unwrap();
+
+        assert_eq!(main_loop.len(), 8);
+    }
+
+    #[test]
+    fn test_compute_tiles_contained_by_main_loop() {
+        let input = "FF7FSF7F7F7F7F7F---7\r\n\
+        L|LJ||||||||||||F--J\r\n\
+        FL-7LJLJ||||||LJL-77\r\n\
+        F--JF--7||LJLJ7F7FJ-\r\n\
+        L---JF-JLJ.||-FJLJJ7\r\n\
+        |F|F-JF---7F7-L7L|7|\r\n\
+        |FFJF7L7F-JF7|JL---7\r\n\
+        7-L-JL7||F7|L7F-7F7|\r\n\
+        L.L7LFJ|||||FJL7||LJ\r\n\
+        L7JLJL-JLJLJL--JLJ.L\r\n";
+        let map = parse_input(input).unwrap();
+        let adjacency_matrix = compute_adjacency_matrix(&map);
+        let main_loop = compute_main_loop(&map, &adjacency_matrix).unwrap();
+        let tiles_contained_by_main_loop = compute_tiles_contained_by_main_loop(&map, &main_loop);
+
+        assert_eq!(tiles_contained_by_main_loop.len(), 10);
+    }
+
+    #[test]
+    fn test_compute_tiles_contained_by_main_loop_2() {
+        let input = ".F----7F7F7F7F-7....\r\n\
+        .|F--7||||||||FJ....\r\n\
+        .||.FJ||||||||L7....\r\n\
+        FJL7L7LJLJ||LJ.L-7..\r\n\
+        L--J.L7...LJS7F-7L7.\r\n\
+        ....F-J..F7FJ|L7L7L7\r\n\
+        ....L7.F7||L7|.L7L7|\r\n\
+        .....|FJLJ|FJ|F7|.LJ\r\n\
+        ....FJL-7.||.||||...\r\n\
+        ....L---J.LJ.LJLJ...\r\n";
+        let map = parse_input(input).unwrap();
+        let adjacency_matrix = compute_adjacency_matrix(&map);
+        let main_loop = compute_main_loop(&map, &adjacency_matrix).unwrap();
+        let tiles_contained_by_main_loop = compute_tiles_contained_by_main_loop(&map, &main_loop);
+
+        assert_eq!(tiles_contained_by_main_loop.len(), 8);
+    }
+
+    #[test]
+    fn test_compute_tiles_contained_by_main_loop_3() {
+        let input = "FF7FSF7F7F7F7F7F---7\r\n\
+        L|LJ||||||||||||F--J\r\n\
+        FL-7LJLJ||||||LJL-77\r\n\
+        F--JF--7||LJLJ7F7FJ-\r\n\
+        L---JF-JLJ.||-FJLJJ7\r\n\
+        |F|F-JF---7F7-L7L|7|\r\n\
+        |FFJF7L7F-JF7|JL---7\r\n\
+        7-L-JL7||F7|L7F-7F7|\r\n\
+        L.L7LFJ|||||FJL7||LJ\r\n\
+        L7JLJL-JLJLJL--JLJ.L\r\n";
+        let map = parse_input(input).unwrap();
+        let adjacency_matrix = compute_adjacency_matrix(&map);
+        let main_loop = compute_main_loop(&map, &adjacency_matrix).unwrap();
+        let tiles_contained_by_main_loop = compute_tiles_contained_by_main_loop(&map, &main_loop);
+
+        assert_eq!(tiles_contained_by_main_loop.len(), 10);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input(".S-7\n.|x|\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidTile('x'));
+        assert_eq!(error.location, Some(Location { line: 2, column: 3 }));
+
+        let error = parse_input(".S-7\n.||\n").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Shape(ShapeError::RaggedRow {
+                expected: 4,
+                found: 3
+            })
+        );
+
+        let error = parse_input("F-7\nL-J\n").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingStart);
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        for input in [
+            ".S-7.\n.|.|.\n.L-..\n",
+            "S-7\n|.-\nL-J\n",
+            "S.\n..\n",
+            "S-\n",
+        ] {
+            let map = parse_input(input).unwrap();
+            let adjacency_matrix = compute_adjacency_matrix(&map);
+            assert_eq!(compute_main_loop(&map, &adjacency_matrix), None);
+            assert_eq!(Day10::part2(&map), Maybe(None));
+        }
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = common::generate::input::<Day10>(seed, Some(30));
+            let map = parse_input(&input).unwrap();
+            let adjacency_matrix = compute_adjacency_matrix(&map);
+            let main_loop = compute_main_loop(&map, &adjacency_matrix).unwrap();
+            assert_eq!(
+                compute_longest_distance(&map, &adjacency_matrix),
+                main_loop.len() / 2
+            );
+            assert!(!compute_tiles_contained_by_main_loop(&map, &main_loop).is_empty());
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use common::Solution;
+use day11::*;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day11>(0, None);
+    common::bench::solution::<Day11>(&mut c.benchmark_group("day11"), input_str);
+
+    // Both parts use whichever of these the `parallel` feature picks
+    let space = Day11::parse(input_str).unwrap();
+    let mut group = c.benchmark_group("day11/total_distance");
+    group.bench_function("serial", |b| {
+        b.iter(|| total_distance(black_box(&space), 1_000_000))
+    });
+    #[cfg(feature = "parallel")]
+    group.bench_function("parallel", |b| {
+        b.iter(|| par_total_distance(black_box(&space), 1_000_000))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
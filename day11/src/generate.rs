@@ -0,0 +1,33 @@
+//! Made-up images of space, `size` pixels square.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day11;
+
+impl Generator for Day11 {
+    const REAL_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        // Some rows and columns stay empty, so there's something to expand
+        let empty_rows = (0..size).map(|_| rng.chance(0.1)).collect::<Vec<_>>();
+        let empty_cols = (0..size).map(|_| rng.chance(0.1)).collect::<Vec<_>>();
+
+        let mut image = vec![vec!['.'; size]; size];
+        for (row, pixels) in image.iter_mut().enumerate() {
+            for (col, pixel) in pixels.iter_mut().enumerate() {
+                if !empty_rows[row] && !empty_cols[col] && rng.chance(0.03) {
+                    *pixel = '#';
+                }
+            }
+        }
+        // At least two galaxies, so there's a pair of them
+        image[0][0] = '#';
+        image[size - 1][size - 1] = '#';
+
+        image
+            .iter()
+            .map(|pixels| pixels.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+
+use common::{geometry::Coord, Solution};
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Space {
+    galaxies: Vec<Coord>,
+}
+
+impl Space {
+    fn distance(&self, from: Coord, to: Coord) -> usize {
+        from.manhattan(to) as usize
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `.` or `#`")]
+    InvalidCharacter(char),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_input(input: &str) -> Result<Space, ParseError> {
+    let mut galaxies: Vec<Coord> = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.char_indices() {
+            match c {
+                '#' => galaxies.push(Coord::from_pos((y, x))),
+                '.' => {}
+                _ => {
+                    let token = &line[x..x + c.len_utf8()];
+                    let kind = ParseErrorKind::InvalidCharacter(c);
+                    return Err(ParseError::new(token, kind).locate(input));
+                }
+            }
+        }
+    }
+
+    Ok(Space { galaxies })
+}
+
+fn expand(space: &Space, expansion_rate: usize) -> Space {
+    let galaxies = &space.galaxies;
+    let expansion_rate = expansion_rate as i64;
+    if galaxies.is_empty() {
+        return Space { galaxies: vec![] };
+    }
+
+    // expand rows
+    let mut galaxies_expanded_rows = Vec::new();
+    let mut expanded_rows = 0;
+
+    let galaxies_by_row: BTreeMap<i64, Vec<Coord>> =
+        galaxies.iter().fold(BTreeMap::new(), |mut acc, galaxy| {
+            acc.entry(galaxy.row).or_default().push(*galaxy);
+            acc
+        });
+
+    // first row isn't expanded
+    for galaxy in galaxies_by_row.values().next().unwrap() {
+        galaxies_expanded_rows.push(*galaxy);
+    }
+
+    for (l_row, (r_row, r_galaxies)) in galaxies_by_row.keys().zip(galaxies_by_row.iter().skip(1)) {
+        let new_rows = (expansion_rate - 1) * (r_row - l_row - 1);
+        expanded_rows += new_rows;
+        for galaxy in r_galaxies {
+            galaxies_expanded_rows.push(*galaxy + Coord::new(expanded_rows, 0));
+        }
+    }
+
+    // expand columns
+    let mut galaxies_expanded = Vec::new();
+    let mut expanded_cols = 0;
+
+    let galaxies_by_col: BTreeMap<i64, Vec<Coord>> =
+        galaxies_expanded_rows
+            .iter()
+            .fold(BTreeMap::new(), |mut acc, galaxy| {
+                acc.entry(galaxy.col).or_default().push(*galaxy);
+                acc
+            });
+
+    // first column isn't expanded
+    for galaxy in galaxies_by_col.values().next().unwrap() {
+        galaxies_expanded.push(*galaxy);
+    }
+
+    for (l_col, (r_col, r_galaxies)) in galaxies_by_col.keys().zip(galaxies_by_col.iter().skip(1)) {
+        let new_cols = (expansion_rate - 1) * (r_col - l_col - 1);
+        expanded_cols += new_cols;
+        for galaxy in r_galaxies {
+            galaxies_expanded.push(*galaxy + Coord::new(0, expanded_cols));
+        }
+    }
+
+    Space {
+        galaxies: galaxies_expanded,
+    }
+}
+
+fn compute_total_distance(space: &Space) -> usize {
+    let mut total_distance = 0;
+    for galaxy_from in &space.galaxies {
+        for galaxy_to in &space.galaxies {
+            if galaxy_from == galaxy_to {
+                continue;
+            }
+            let distance = space.distance(*galaxy_from, *galaxy_to);
+            total_distance += distance;
+        }
+    }
+    total_distance / 2
+}
+
+/// [`compute_total_distance`], with the galaxies to measure from spread over all cores.
+#[cfg(feature = "parallel")]
+fn par_compute_total_distance(space: &Space) -> usize {
+    use rayon::prelude::*;
+
+    let total_distance = space
+        .galaxies
+        .par_iter()
+        .map(|galaxy_from| {
+            space
+                .galaxies
+                .iter()
+                .map(|galaxy_to| space.distance(*galaxy_from, *galaxy_to))
+                .sum::<usize>()
+        })
+        .sum::<usize>();
+    total_distance / 2
+}
+
+pub fn total_distance(space: &Space, expansion_rate: usize) -> usize {
+    compute_total_distance(&expand(space, expansion_rate))
+}
+
+#[cfg(feature = "parallel")]
+pub fn par_total_distance(space: &Space, expansion_rate: usize) -> usize {
+    par_compute_total_distance(&expand(space, expansion_rate))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Space;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(space: &Self::Parsed<'_>) -> Self::Answer1 {
+        #[cfg(feature = "parallel")]
+        let total_distance = par_total_distance;
+        total_distance(space, 2)
+    }
+
+    fn part2(space: &Self::Parsed<'_>) -> Self::Answer2 {
+        #[cfg(feature = "parallel")]
+        let total_distance = par_total_distance;
+        total_distance(space, 1_000_000)
+    }
+}
+
+#[test]
+fn test_distance() {
+    let input = include_str!("../sample.txt");
+    let space = expand(&parse_input(input).unwrap(), 2);
+
+    let from_galaxy = Coord::new(0, 4);
+    let to_galaxy = Coord::new(10, 9);
+    assert!(space.galaxies.contains(&from_galaxy));
+    assert!(space.galaxies.contains(&to_galaxy));
+    assert_eq!(space.distance(from_galaxy, to_galaxy), 15);
+    assert_eq!(space.distance(to_galaxy, from_galaxy), 15);
+
+    let from_galaxy = Coord::new(2, 0);
+    let to_galaxy = Coord::new(7, 12);
+    assert!(space.galaxies.contains(&from_galaxy));
+    assert!(space.galaxies.contains(&to_galaxy));
+    assert_eq!(space.distance(from_galaxy, to_galaxy), 17);
+    assert_eq!(space.distance(to_galaxy, from_galaxy), 17);
+
+    let from_galaxy = Coord::new(11, 0);
+    let to_galaxy = Coord::new(11, 5);
+    assert!(space.galaxies.contains(&from_galaxy));
+    assert!(space.galaxies.contains(&to_galaxy));
+    assert_eq!(space.distance(from_galaxy, to_galaxy), 5);
+    assert_eq!(space.distance(to_galaxy, from_galaxy), 5);
+}
+
+#[test]
+fn test_total_distance_1() {
+    let input = include_str!("../sample.txt");
+    let space = expand(&parse_input(input).unwrap(), 2);
+    let total_distance = compute_total_distance(&space);
+    assert_eq!(total_distance, 374);
+}
+
+#[test]
+fn test_total_distance_10() {
+    let input = include_str!("../sample.txt");
+    let space = expand(&parse_input(input).unwrap(), 10);
+    let total_distance = compute_total_distance(&space);
+    assert_eq!(total_distance, 1030);
+}
+
+#[test]
+fn test_total_distance_100() {
+    let input = include_str!("../sample.txt");
+    let space = expand(&parse_input(input).unwrap(), 100);
+    let total_distance = compute_total_distance(&space);
+    assert_eq!(total_distance, 8410);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("...#\n#.o.\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidCharacter('o'));
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 3 })
+    );
+}
+
+#[test]
+fn test_no_galaxies() {
+    let space = parse_input("...\n...\n").unwrap();
+    assert_eq!(total_distance(&space, 2), 0);
+}
+
+#[test]
+fn test_generated() {
+    // Every empty line a path crosses adds the same to it, so the total grows linearly
+    for seed in 0..10 {
+        let input = common::generate::input::<Day11>(seed, Some(30));
+        let space = parse_input(&input).unwrap();
+        let [once, ten, hundred] = [1, 10, 100].map(|rate| total_distance(&space, rate));
+        assert_eq!(hundred - ten, 10 * (ten - once));
+        #[cfg(feature = "parallel")]
+        assert_eq!(par_total_distance(&space, 10), ten);
+    }
+}
@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use common::Solution;
+use day12::*;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day12>(0, None);
+
+    // Solving takes seconds, so keep to the minimum number of samples
+    let mut group = c.benchmark_group("day12");
+    group.sample_size(10);
+    common::bench::solution::<Day12>(&mut group, input_str);
+    group.finish();
+
+    // Part 2 is whichever of these the `parallel` feature picks
+    let rows = Day12::parse(input_str).unwrap();
+    let mut group = c.benchmark_group("day12/unfolded_arrangements");
+    group.sample_size(10);
+    group.bench_function("serial", |b| {
+        b.iter(|| unfolded_arrangements(black_box(&rows)))
+    });
+    #[cfg(feature = "parallel")]
+    group.bench_function("parallel", |b| {
+        b.iter(|| par_unfolded_arrangements(black_box(&rows)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+//! Made-up condition records of `size` rows, each with at least one arrangement: the one the
+//! row was made from before some of its springs were hidden.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day12;
+
+impl Generator for Day12 {
+    const REAL_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let length = rng.between(4, 20) as usize;
+            let mut springs = (0..length)
+                .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            springs[rng.index(length)] = '#';
+
+            let groups = springs
+                .split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+            for spring in &mut springs {
+                if rng.chance(0.4) {
+                    *spring = '?';
+                }
+            }
+
+            input.push_str(&format!(
+                "{} {}\n",
+                springs.iter().collect::<String>(),
+                groups.join(",")
+            ));
+        }
+        input
+    }
+}
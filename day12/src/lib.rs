@@ -0,0 +1,317 @@
+use std::{collections::HashMap, num::ParseIntError};
+
+use common::Solution;
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `#`, `.` or `?`")]
+    InvalidSpring(char),
+    #[error("missing group sizes")]
+    MissingGroups,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+enum Spring {
+    Working,
+    Broken,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Spring::Working),
+            '.' => Ok(Spring::Broken),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(ParseErrorKind::InvalidSpring(value)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Row {
+    springs: Vec<Spring>,
+    contiguous_working_springs: Vec<usize>,
+}
+
+impl TryFrom<&str> for Row {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (springs, groups) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(value, ParseErrorKind::MissingGroups))?;
+
+        let springs = springs
+            .char_indices()
+            .map(|(i, c)| {
+                c.try_into()
+                    .map_err(|kind| ParseError::new(&springs[i..i + c.len_utf8()], kind))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let contiguous_working_springs = groups
+            .trim()
+            .split(',')
+            .map(|s| {
+                s.parse()
+                    .map_err(|e| ParseError::new(s, ParseErrorKind::InvalidNumber(e)))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Row {
+            springs,
+            contiguous_working_springs,
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Row>, ParseError> {
+    input
+        .lines()
+        .map(Row::try_from)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(input))
+}
+
+type Cache = HashMap<(Vec<Spring>, Option<usize>, Vec<usize>), usize>;
+
+fn explore_row(
+    cache: &mut Cache,
+    springs: &[Spring],
+    current_group: Option<usize>,
+    remaining_continuous: &[usize],
+) -> usize {
+    if springs.is_empty() {
+        return match (current_group, remaining_continuous.len()) {
+            (None, 0) => 1,
+            (Some(x), 1) if x == remaining_continuous[0] => 1,
+            _ => 0,
+        };
+    }
+
+    if current_group.is_some() && remaining_continuous.is_empty() {
+        // couldn't clone working group
+        return 0;
+    }
+
+    if let Some(ans) = cache.get(&(
+        springs.to_vec(),
+        current_group,
+        remaining_continuous.to_vec(),
+    )) {
+        return *ans;
+    }
+
+    let ans = match (springs[0], current_group) {
+        (Spring::Broken, Some(x)) if x != remaining_continuous[0] => 0,
+        (Spring::Broken, Some(_)) => {
+            explore_row(cache, &springs[1..], None, &remaining_continuous[1..])
+        }
+        (Spring::Broken, None) => explore_row(cache, &springs[1..], None, remaining_continuous),
+        (Spring::Working, Some(_)) => explore_row(
+            cache,
+            &springs[1..],
+            current_group.map(|x| x + 1),
+            remaining_continuous,
+        ),
+        (Spring::Working, None) => explore_row(cache, &springs[1..], Some(1), remaining_continuous),
+        (Spring::Unknown, Some(x)) => {
+            let mut ans = explore_row(
+                cache,
+                &springs[1..],
+                current_group.map(|x| x + 1),
+                remaining_continuous,
+            );
+            if x == remaining_continuous[0] {
+                ans += explore_row(cache, &springs[1..], None, &remaining_continuous[1..])
+            }
+            ans
+        }
+        (Spring::Unknown, None) => {
+            explore_row(cache, &springs[1..], Some(1), remaining_continuous)
+                + explore_row(cache, &springs[1..], None, remaining_continuous)
+        }
+    };
+
+    cache.insert(
+        (
+            springs.to_vec(),
+            current_group,
+            remaining_continuous.to_vec(),
+        ),
+        ans,
+    );
+
+    ans
+}
+
+fn possible_arrangements(row: &Row) -> usize {
+    explore_row(
+        &mut HashMap::new(),
+        &row.springs,
+        None,
+        &row.contiguous_working_springs,
+    )
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Vec<Row>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(rows: &Self::Parsed<'_>) -> Self::Answer1 {
+        rows.iter().map(possible_arrangements).sum::<usize>()
+    }
+
+    fn part2(rows: &Self::Parsed<'_>) -> Self::Answer2 {
+        #[cfg(feature = "parallel")]
+        let unfolded_arrangements = par_unfolded_arrangements;
+        unfolded_arrangements(rows)
+    }
+}
+
+/// The row five times over, as part 2 reads it.
+fn unfold(row: &Row) -> Row {
+    Row {
+        springs: vec![row.springs.clone(); 5].join(&Spring::Unknown),
+        contiguous_working_springs: row.contiguous_working_springs.repeat(5),
+    }
+}
+
+/// The sum of the possible arrangements of every row once unfolded.
+pub fn unfolded_arrangements(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|row| possible_arrangements(&unfold(row)))
+        .sum()
+}
+
+/// [`unfolded_arrangements`], with the rows spread over all cores.
+#[cfg(feature = "parallel")]
+pub fn par_unfolded_arrangements(rows: &[Row]) -> usize {
+    use rayon::prelude::*;
+
+    rows.par_iter()
+        .map(|row| possible_arrangements(&unfold(row)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+
+    #[test]
+    fn test_explore_row() {
+        let input = parse_input("#.#.### 1,1,3").unwrap();
+        let row = &input[0];
+        assert_eq!(possible_arrangements(row), 1);
+    }
+
+    #[test]
+    fn test_explore_row_2() {
+        let input = parse_input("???.### 1,1,3").unwrap();
+        let row = &input[0];
+        assert_eq!(possible_arrangements(row), 1);
+    }
+
+    #[test]
+    fn test_explore_row_3() {
+        let input = parse_input(".??..??...?##. 1,1,3").unwrap();
+        let row = &input[0];
+        assert_eq!(possible_arrangements(row), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("#.#.### 1,1,3\n???.#x# 1,1,3").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidSpring('x'));
+        assert_eq!(error.location, Some(Location { line: 2, column: 6 }));
+
+        let error = parse_input("???.### 1,,3").unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::InvalidNumber(_)));
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 1,
+                column: 11
+            })
+        );
+
+        let error = parse_input("???.###").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MissingGroups);
+    }
+
+    /// Counts arrangements by trying every way to fill in the unknown springs.
+    fn brute_force_arrangements(row: &Row) -> usize {
+        let unknowns = row
+            .springs
+            .iter()
+            .filter(|&&spring| spring == Spring::Unknown)
+            .count();
+        (0..1 << unknowns)
+            .filter(|choice| {
+                let mut unknown = 0;
+                let mut groups = vec![];
+                let mut run = 0;
+                for &spring in &row.springs {
+                    let working = match spring {
+                        Spring::Unknown => {
+                            unknown += 1;
+                            choice & 1 << (unknown - 1) != 0
+                        }
+                        spring => spring == Spring::Working,
+                    };
+                    if working {
+                        run += 1;
+                    } else if run > 0 {
+                        groups.push(run);
+                        run = 0;
+                    }
+                }
+                if run > 0 {
+                    groups.push(run);
+                }
+                groups == row.contiguous_working_springs
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = common::generate::input::<Day12>(seed, Some(20));
+            for row in parse_input(&input).unwrap() {
+                let arrangements = possible_arrangements(&row);
+                // The row was made from one of them
+                assert!(arrangements > 0);
+                assert_eq!(arrangements, brute_force_arrangements(&row));
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_unfolded_arrangements() {
+        let rows = parse_input(&common::generate::input::<Day12>(0, Some(20))).unwrap();
+        assert_eq!(
+            par_unfolded_arrangements(&rows),
+            unfolded_arrangements(&rows)
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day13::Day13;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day13>(0, None);
+    common::bench::solution::<Day13>(&mut c.benchmark_group("day13"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
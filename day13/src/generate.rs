@@ -0,0 +1,76 @@
+//! Made-up notes of `size` patterns, each with a line of reflection and a smudge that hides
+//! another one.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day13;
+
+/// A pattern reflected across a vertical line, and across a horizontal one but for one smudge.
+fn pattern(rng: &mut Rng) -> Vec<Vec<bool>> {
+    let height = rng.between(5, 17) as usize;
+    let width = rng.between(5, 17) as usize;
+    let mut cells = (0..height)
+        .map(|_| (0..width).map(|_| rng.chance(0.5)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // Off the middle, so some columns have nothing to mirror and can hide the smudge
+    let column = loop {
+        let column = rng.between(1, width as i64 - 1) as usize;
+        if 2 * column != width {
+            break column;
+        }
+    };
+    let column_span = column.min(width - column);
+    for row in &mut cells {
+        for j in 0..column_span {
+            row[column + j] = row[column - 1 - j];
+        }
+    }
+
+    let row = rng.between(1, height as i64 - 1) as usize;
+    let row_span = row.min(height - row);
+    for j in 0..row_span {
+        cells[row + j] = cells[row - 1 - j].clone();
+    }
+
+    // Flipping a cell the column line doesn't mirror keeps that reflection, but not the other
+    let smudge_row = rng.between((row - row_span) as i64, (row + row_span - 1) as i64) as usize;
+    let unmirrored = (0..width)
+        .filter(|&col| col < column - column_span || col >= column + column_span)
+        .collect::<Vec<_>>();
+    let smudge_col = *rng.choose(&unmirrored);
+    cells[smudge_row][smudge_col] ^= true;
+
+    cells
+}
+
+fn transpose(cells: Vec<Vec<bool>>) -> Vec<Vec<bool>> {
+    (0..cells[0].len())
+        .map(|col| cells.iter().map(|row| row[col]).collect())
+        .collect()
+}
+
+impl Generator for Day13 {
+    const REAL_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let patterns = (0..size)
+            .map(|_| {
+                let cells = pattern(rng);
+                let cells = if rng.chance(0.5) {
+                    transpose(cells)
+                } else {
+                    cells
+                };
+                cells
+                    .iter()
+                    .map(|row| {
+                        let row = row.iter().map(|&rock| if rock { '#' } else { '.' });
+                        row.collect::<String>() + "\n"
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        patterns.join("\n")
+    }
+}
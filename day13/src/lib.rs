@@ -0,0 +1,210 @@
+use common::{Maybe, Solution};
+use grid::{Grid, ShapeError};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("expected `.` or `#`")]
+    InvalidCharacter(char),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+/// Rocks are `true`, ash is `false`.
+type Pattern = Grid<bool>;
+
+/// The blocks of lines between blank lines, as slices of `input` so errors can be located in it.
+fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut start = 0;
+    let mut end = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if end > start {
+                blocks.push(&input[start..end]);
+            }
+            start = end + line.len();
+        }
+        end += line.len();
+    }
+    if end > start {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
+
+fn parse_pattern(block: &str) -> Result<Pattern, ParseError> {
+    Grid::parse_with(block, |_, c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseErrorKind::InvalidCharacter(c)),
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let blocks = blocks(input);
+    if blocks.is_empty() {
+        return Err(ParseError::whole_input(ShapeError::Empty.into()));
+    }
+    blocks
+        .into_iter()
+        .map(|block| {
+            parse_pattern(block).map_err(|mut e| {
+                // Located within the block, and wanted within the whole input
+                e.location = None;
+                e.locate(input)
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_input() {
+    let input = include_str!("../sample.txt");
+    let patterns = parse_input(input).unwrap();
+    assert_eq!(patterns.len(), 2);
+    assert_eq!((patterns[0].height(), patterns[0].width()), (7, 9));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("#.#\n.#.\n\n##\n#.#\n").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Shape(ShapeError::RaggedRow {
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 5, column: 1 })
+    );
+
+    let error = parse_input("#.#\n\n#.#\n.x.\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidCharacter('x'));
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 4, column: 2 })
+    );
+
+    let error = parse_input("\n\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::Shape(ShapeError::Empty));
+}
+
+/// How many cells differ between two lines of cells.
+fn differences<'a>(a: impl Iterator<Item = &'a bool>, b: impl Iterator<Item = &'a bool>) -> usize {
+    a.zip(b).filter(|(a, b)| a != b).count()
+}
+
+fn vertical_reflection_errors(pattern: &Pattern, reflects_after: usize) -> usize {
+    let max_reflection_len = usize::min(reflects_after + 1, pattern.width() - 1 - reflects_after);
+
+    (0..max_reflection_len)
+        .map(|i| {
+            let left = pattern.column(reflects_after - i);
+            differences(left, pattern.column(reflects_after + 1 + i))
+        })
+        .sum()
+}
+
+fn find_vertical_reflection(pattern: &Pattern, errors: usize) -> Option<usize> {
+    (0..pattern.width() - 1)
+        .find(|&i| vertical_reflection_errors(pattern, i) == errors)
+        .map(|x| x + 1)
+}
+
+fn horizontal_reflection_errors(pattern: &Pattern, reflects_after: usize) -> usize {
+    let max_reflection_len = usize::min(reflects_after + 1, pattern.height() - 1 - reflects_after);
+
+    (0..max_reflection_len)
+        .map(|i| {
+            let top = pattern.row(reflects_after - i).iter();
+            differences(top, pattern.row(reflects_after + 1 + i).iter())
+        })
+        .sum()
+}
+
+fn find_horizontal_reflection(pattern: &Pattern, errors: usize) -> Option<usize> {
+    (0..pattern.height() - 1)
+        .find(|&i| horizontal_reflection_errors(pattern, i) == errors)
+        .map(|x| x + 1)
+}
+
+fn find_reflection(pattern: &Pattern, errors: usize) -> (Option<usize>, Option<usize>) {
+    let v = find_vertical_reflection(pattern, errors);
+    if v.is_some() {
+        (v, None)
+    } else {
+        (None, find_horizontal_reflection(pattern, errors))
+    }
+}
+
+/// The summary of every pattern's reflection, unless one of them doesn't have any.
+fn summarize_reflections(
+    reflections: impl Iterator<Item = (Option<usize>, Option<usize>)>,
+) -> Option<usize> {
+    reflections
+        .map(|(x, y)| match (x, y) {
+            (_, Some(y)) => Some(100 * y),
+            (Some(x), _) => Some(x),
+            _ => None,
+        })
+        .sum::<Option<usize>>()
+}
+
+fn part_1(patterns: &[Pattern]) -> Option<usize> {
+    let reflections = patterns.iter().map(|p| find_reflection(p, 0));
+    summarize_reflections(reflections)
+}
+
+fn part_2(patterns: &[Pattern]) -> Option<usize> {
+    let reflections = patterns.iter().map(|p| find_reflection(p, 1));
+    summarize_reflections(reflections)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Pattern>;
+    type Error = ParseError;
+    type Answer1 = Maybe<usize>;
+    type Answer2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(patterns: &Self::Parsed<'_>) -> Self::Answer1 {
+        part_1(patterns).into()
+    }
+
+    fn part2(patterns: &Self::Parsed<'_>) -> Self::Answer2 {
+        part_2(patterns).into()
+    }
+}
+
+#[test]
+fn test_generated() {
+    // Every pattern has one clean reflection and another with a smudge
+    for seed in 0..10 {
+        let input = common::generate::input::<Day13>(seed, Some(20));
+        let patterns = parse_input(&input).unwrap();
+        assert_eq!(patterns.len(), 20);
+        for pattern in &patterns {
+            assert_ne!(find_reflection(pattern, 0), (None, None));
+            assert_ne!(find_reflection(pattern, 1), (None, None));
+        }
+    }
+}
+
+#[test]
+fn test_no_reflection() {
+    let patterns = parse_input("#.\n..\n").unwrap();
+    assert_eq!(part_1(&patterns), None);
+    let patterns = parse_input("#\n").unwrap();
+    assert_eq!(part_2(&patterns), None);
+}
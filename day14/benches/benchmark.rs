@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use day14::*;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day14>(0, None);
+
+    let mut group = c.benchmark_group("day14");
+    group.sample_size(10);
+    common::bench::solution::<Day14>(&mut group, input_str);
+    group.finish();
+
+    // The naive version can't reach part 2's billion cycles, so compare on fewer
+    let grid = parse_grid(input_str).unwrap();
+    let mut group = c.benchmark_group("day14/cycles");
+    group.sample_size(10);
+    for iterations in [100, 1000] {
+        group.bench_with_input(
+            BenchmarkId::new("cycles_naive", iterations),
+            &iterations,
+            |b, &iterations| b.iter(|| cycles_naive(black_box(grid.clone()), iterations)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("cycles_cached", iterations),
+            &iterations,
+            |b, &iterations| b.iter(|| cycles_cached(black_box(grid.clone()), iterations)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("cycles_brent", iterations),
+            &iterations,
+            |b, &iterations| b.iter(|| cycles_brent(black_box(grid.clone()), iterations)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+//! Made-up platforms, `size` tiles square.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day14;
+
+impl Generator for Day14 {
+    const REAL_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(match rng.below(100) {
+                    0..=19 => 'O',
+                    20..=34 => '#',
+                    _ => '.',
+                });
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
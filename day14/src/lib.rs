@@ -0,0 +1,201 @@
+use common::{
+    cycle::{self, Method},
+    geometry::Direction,
+    Solution,
+};
+use grid::ShapeError;
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("expected `O`, `#` or `.`")]
+    InvalidCharacter(char),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+pub type Grid = grid::Grid<char>;
+
+pub fn parse_grid(input: &str) -> Result<Grid, ParseError> {
+    Grid::parse_with(input, |_, c| match c {
+        'O' | '#' | '.' => Ok(c),
+        _ => Err(ParseErrorKind::InvalidCharacter(c)),
+    })
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_grid("O.#\n.?.\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidCharacter('?'));
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 2 })
+    );
+
+    let error = parse_grid("O.#\n..\n").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Shape(ShapeError::RaggedRow {
+            expected: 3,
+            found: 2
+        })
+    );
+}
+
+/// Rolls every round rock in `line` towards its start, until it hits a cube rock or another
+/// round rock.
+fn roll_rocks<'a>(line: impl Iterator<Item = &'a mut char>) {
+    let mut line = line.collect::<Vec<_>>();
+    let mut free = 0;
+    for i in 0..line.len() {
+        match *line[i] {
+            '#' => free = i + 1,
+            'O' => {
+                *line[i] = '.';
+                *line[free] = 'O';
+                free += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn tilt_grid(grid: &mut Grid, direction: Direction) {
+    match direction {
+        Direction::North => {
+            for col in 0..grid.width() {
+                roll_rocks(grid.column_mut(col));
+            }
+        }
+        Direction::South => {
+            for col in 0..grid.width() {
+                roll_rocks(grid.column_mut(col).rev());
+            }
+        }
+        Direction::East => {
+            for row in 0..grid.height() {
+                roll_rocks(grid.row_mut(row).iter_mut().rev());
+            }
+        }
+        Direction::West => {
+            for row in 0..grid.height() {
+                roll_rocks(grid.row_mut(row).iter_mut());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tilt_grid() {
+    let grid_str = include_str!("../sample.txt");
+    let mut grid = parse_grid(grid_str).unwrap();
+
+    let target_grid_str = include_str!("../sample_tilted.txt");
+    let target_grid = parse_grid(target_grid_str).unwrap();
+
+    tilt_grid(&mut grid, Direction::North);
+    assert_eq!(grid, target_grid);
+}
+
+fn tilt_grid_cycle(grid: &mut Grid) {
+    tilt_grid(grid, Direction::North);
+    tilt_grid(grid, Direction::West);
+    tilt_grid(grid, Direction::South);
+    tilt_grid(grid, Direction::East);
+}
+
+#[test]
+fn test_tilt_grid_cycle() {
+    let grid_str = include_str!("../sample.txt");
+    let mut grid = parse_grid(grid_str).unwrap();
+
+    let target_grid_str = include_str!("../sample_cycle.txt");
+    let target_grid = parse_grid(target_grid_str).unwrap();
+
+    tilt_grid_cycle(&mut grid);
+    assert_eq!(grid, target_grid);
+}
+
+pub fn cycles_naive(mut grid: Grid, iterations: usize) -> Grid {
+    for _ in 0..iterations {
+        tilt_grid_cycle(&mut grid);
+    }
+    grid
+}
+
+/// Skips ahead once the grid repeats, remembering a fingerprint of every grid seen.
+pub fn cycles_cached(grid: Grid, iterations: usize) -> Grid {
+    cycle::state_at(grid, tilt_grid_cycle, iterations, Method::Fingerprint).state
+}
+
+/// Skips ahead once the grid repeats, found with Brent's algorithm.
+pub fn cycles_brent(grid: Grid, iterations: usize) -> Grid {
+    cycle::state_at(grid, tilt_grid_cycle, iterations, Method::Brent).state
+}
+
+fn compute_total_load(grid: &Grid) -> usize {
+    let mut total_load = 0;
+    for (j, row) in grid.rows().rev().enumerate() {
+        total_load += row.iter().filter(|&&c| c == 'O').count() * (j + 1);
+    }
+    total_load
+}
+
+#[test]
+fn test_cycles_cached() {
+    let grid = parse_grid(include_str!("../sample.txt")).unwrap();
+    let naive = cycles_naive(grid.clone(), 1000);
+    assert_eq!(cycles_cached(grid.clone(), 1000), naive);
+    assert_eq!(cycles_brent(grid.clone(), 1000), naive);
+}
+
+#[test]
+fn test_total_load() {
+    let grid_str = include_str!("../sample_tilted.txt");
+    let grid = parse_grid(grid_str).unwrap();
+    assert_eq!(compute_total_load(&grid), 136);
+}
+
+#[test]
+fn test_generated() {
+    for seed in 0..10 {
+        let grid = parse_grid(&common::generate::input::<Day14>(seed, Some(20))).unwrap();
+        let mut tilted = grid.clone();
+        tilt_grid(&mut tilted, Direction::North);
+        let mut tilted_again = tilted.clone();
+        tilt_grid(&mut tilted_again, Direction::North);
+        assert_eq!(tilted_again, tilted);
+        assert_eq!(Day14::part1(&grid), compute_total_load(&tilted));
+
+        let naive = cycles_naive(grid.clone(), 200);
+        assert_eq!(cycles_cached(grid.clone(), 200), naive);
+        assert_eq!(cycles_brent(grid.clone(), 200), naive);
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Grid;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Self::Answer1 {
+        let mut grid = grid.clone();
+        tilt_grid(&mut grid, Direction::North);
+        compute_total_load(&grid)
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Self::Answer2 {
+        let grid = cycles_cached(grid.clone(), 1_000_000_000);
+        compute_total_load(&grid)
+    }
+}
@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day15::Day15;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day15>(0, None);
+    common::bench::solution::<Day15>(&mut c.benchmark_group("day15"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
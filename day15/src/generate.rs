@@ -0,0 +1,32 @@
+//! Made-up initialization sequences of `size` steps.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day15;
+
+impl Generator for Day15 {
+    const REAL_SIZE: usize = 4000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Few enough labels that they keep coming back, to be replaced or removed
+        let labels = (0..size / 8 + 1)
+            .map(|_| {
+                (0..rng.between(2, 6))
+                    .map(|_| char::from(b'a' + rng.below(26) as u8))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        let steps = (0..size)
+            .map(|_| {
+                let label = rng.choose(&labels);
+                if rng.chance(0.6) {
+                    format!("{label}={}", rng.between(1, 9))
+                } else {
+                    format!("{label}-")
+                }
+            })
+            .collect::<Vec<_>>();
+        steps.join(",") + "\n"
+    }
+}
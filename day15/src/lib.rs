@@ -0,0 +1,194 @@
+use std::num::ParseIntError;
+
+use common::Solution;
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("empty initialization sequence")]
+    Empty,
+    #[error("expected `<label>=<focal length>` or `<label>-`")]
+    MissingOperation,
+    #[error("invalid focal length: {0}")]
+    InvalidFocalLength(#[from] ParseIntError),
+    #[error("focal lengths must be between 1 and 9")]
+    FocalLengthOutOfRange,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Insert(usize),
+    Remove,
+}
+
+#[derive(Debug)]
+pub struct Step<'a> {
+    raw: &'a str,
+    label: &'a str,
+    operation: Operation,
+}
+
+fn parse_focal_length(token: &str) -> Result<usize, ParseError> {
+    let focal_length = token
+        .parse()
+        .map_err(|e| ParseError::new(token, ParseErrorKind::InvalidFocalLength(e)))?;
+    if !(1..=9).contains(&focal_length) {
+        return Err(ParseError::new(
+            token,
+            ParseErrorKind::FocalLengthOutOfRange,
+        ));
+    }
+    Ok(focal_length)
+}
+
+fn parse_step(step: &str) -> Result<Step<'_>, ParseError> {
+    let label_op_idx = step
+        .find(['-', '='])
+        .ok_or_else(|| ParseError::new(step, ParseErrorKind::MissingOperation))?;
+    let (label, op) = step.split_at(label_op_idx);
+    let operation = match op.split_at(1) {
+        ("=", focal_length) => Operation::Insert(parse_focal_length(focal_length)?),
+        ("-", "") => Operation::Remove,
+        _ => return Err(ParseError::new(step, ParseErrorKind::MissingOperation)),
+    };
+
+    Ok(Step {
+        raw: step,
+        label,
+        operation,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::whole_input(ParseErrorKind::Empty))?;
+
+    line.split(',')
+        .map(parse_step)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(input))
+}
+
+fn hash<'a>(input: impl Into<&'a [u8]>) -> u8 {
+    let mut current: u8 = 0;
+
+    for c in input.into() {
+        current = current.overflowing_add(*c).0;
+        current = current.overflowing_mul(17).0;
+    }
+
+    current
+}
+
+fn arrange_boxes<'a>(lenses: &[Step<'a>]) -> [Vec<(&'a str, usize)>; 256] {
+    let mut boxes: [Vec<(&'a str, usize)>; 256] = core::array::from_fn(|_| Vec::new());
+
+    for lens in lenses {
+        let box_idx = hash(lens.label.as_bytes());
+        let target_box = &mut boxes[box_idx as usize];
+        match lens.operation {
+            Operation::Insert(focal_length) => {
+                if let Some(existing_idx) = target_box
+                    .iter()
+                    .position(|(label, _)| *label == lens.label)
+                {
+                    target_box[existing_idx].1 = focal_length;
+                } else {
+                    target_box.push((lens.label, focal_length));
+                }
+            }
+            Operation::Remove => {
+                if let Some(target_idx) = target_box
+                    .iter()
+                    .position(|(label, _)| *label == lens.label)
+                {
+                    target_box.remove(target_idx);
+                }
+            }
+        }
+    }
+
+    boxes
+}
+
+fn compute_focusing_power(boxes: &[Vec<(&str, usize)>; 256]) -> usize {
+    let mut result = 0;
+
+    for (i, b) in boxes.iter().enumerate() {
+        for (slot, (_, focal_length)) in b.iter().enumerate() {
+            result += (i + 1) * (slot + 1) * focal_length;
+        }
+    }
+
+    result
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Vec<Step<'a>>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> Self::Answer1 {
+        steps
+            .iter()
+            .map(|step| hash(step.raw.as_bytes()) as usize)
+            .sum::<usize>()
+    }
+
+    fn part2(steps: &Self::Parsed<'_>) -> Self::Answer2 {
+        let boxes = arrange_boxes(steps);
+        compute_focusing_power(&boxes)
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("rn=1,cm-,qp=x,cm=2").unwrap_err();
+    assert!(matches!(error.kind, ParseErrorKind::InvalidFocalLength(_)));
+    assert_eq!(
+        error.location,
+        Some(common::Location {
+            line: 1,
+            column: 13
+        })
+    );
+
+    let error = parse_input("rn=1,cm=10").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::FocalLengthOutOfRange);
+
+    let error = parse_input("rn=1,cm").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::MissingOperation);
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 1, column: 6 })
+    );
+}
+
+#[test]
+fn test_generated() {
+    for seed in 0..10 {
+        let input = common::generate::input::<Day15>(seed, Some(200));
+        let steps = parse_input(&input).unwrap();
+        assert_eq!(steps.len(), 200);
+        let boxes = arrange_boxes(&steps);
+        for (i, lenses) in boxes.iter().enumerate() {
+            for (label, _) in lenses {
+                assert_eq!(hash(label.as_bytes()) as usize, i);
+                assert_eq!(lenses.iter().filter(|(l, _)| l == label).count(), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use common::Solution;
+use day16::*;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day16>(0, None);
+    common::bench::solution::<Day16>(&mut c.benchmark_group("day16"), input_str);
+
+    // Part 2 is whichever of these the `parallel` feature picks
+    let grid = Day16::parse(input_str).unwrap();
+    let mut group = c.benchmark_group("day16/max_energized_tiles");
+    group.sample_size(10);
+    group.bench_function("serial", |b| {
+        b.iter(|| max_energized_tiles(black_box(&grid)))
+    });
+    #[cfg(feature = "parallel")]
+    group.bench_function("parallel", |b| {
+        b.iter(|| par_max_energized_tiles(black_box(&grid)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+//! Made-up contraptions, `size` tiles square.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day16;
+
+impl Generator for Day16 {
+    const REAL_SIZE: usize = 110;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..size {
+                let tile = if rng.chance(0.1) {
+                    *rng.choose(&['/', '\\', '|', '-'])
+                } else {
+                    '.'
+                };
+                input.push(tile);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
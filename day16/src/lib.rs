@@ -0,0 +1,189 @@
+use core::panic;
+use std::collections::HashSet;
+
+use common::{
+    geometry::{Coord, Direction},
+    Solution,
+};
+use grid::{Grid, ShapeError};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("expected one of `./\\-|`")]
+    InvalidCell(char),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_input(input_str: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_with(input_str, |_, c| match c {
+        '.' | '/' | '\\' | '-' | '|' => Ok(c),
+        _ => Err(ParseErrorKind::InvalidCell(c)),
+    })
+}
+
+type BeamPath = HashSet<(Coord, Direction)>;
+
+fn next_beam(grid: &Grid<char>, previous_path: &mut BeamPath, pos: Coord, dir: Direction) {
+    if previous_path.contains(&(pos, dir)) {
+        // loop
+        return;
+    }
+
+    let current_cell = grid.at(pos);
+    if let Some(current_cell) = current_cell {
+        previous_path.insert((pos, dir));
+        match current_cell {
+            '.' => {
+                next_beam(grid, previous_path, pos.step(dir), dir);
+            }
+            '/' => {
+                let dir = if dir.is_vertical() {
+                    dir.turn_right()
+                } else {
+                    dir.turn_left()
+                };
+                next_beam(grid, previous_path, pos.step(dir), dir);
+            }
+            '\\' => {
+                let dir = if dir.is_vertical() {
+                    dir.turn_left()
+                } else {
+                    dir.turn_right()
+                };
+                next_beam(grid, previous_path, pos.step(dir), dir);
+            }
+            '-' => {
+                if !dir.is_vertical() {
+                    next_beam(grid, previous_path, pos.step(dir), dir);
+                } else {
+                    for dir in [Direction::West, Direction::East] {
+                        next_beam(grid, previous_path, pos.step(dir), dir);
+                    }
+                }
+            }
+            '|' => {
+                if dir.is_vertical() {
+                    next_beam(grid, previous_path, pos.step(dir), dir)
+                } else {
+                    for dir in [Direction::South, Direction::North] {
+                        next_beam(grid, previous_path, pos.step(dir), dir);
+                    }
+                }
+            }
+            _ => panic!("invalid cell, got {}", current_cell),
+        };
+    }
+}
+
+fn count_energized_tiles(
+    grid: &Grid<char>,
+    starting_position: Coord,
+    starting_direction: Direction,
+) -> usize {
+    let mut path = HashSet::new();
+    next_beam(grid, &mut path, starting_position, starting_direction);
+    path.into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Grid<char>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Self::Answer1 {
+        count_energized_tiles(grid, Coord::ORIGIN, Direction::East)
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Self::Answer2 {
+        #[cfg(feature = "parallel")]
+        let max_energized_tiles = par_max_energized_tiles;
+        max_energized_tiles(grid)
+    }
+}
+
+/// Every cell on the border with the direction pointing into the grid from it, twice for corners.
+fn edge_beams(grid: &Grid<char>) -> Vec<(Coord, Direction)> {
+    let last_row = grid.height().saturating_sub(1);
+    let last_col = grid.width().saturating_sub(1);
+    let rows = (0..grid.height()).flat_map(|i| {
+        [
+            (Coord::from_pos((i, 0)), Direction::East),
+            (Coord::from_pos((i, last_col)), Direction::West),
+        ]
+    });
+    let cols = (0..grid.width()).flat_map(|i| {
+        [
+            (Coord::from_pos((0, i)), Direction::South),
+            (Coord::from_pos((last_row, i)), Direction::North),
+        ]
+    });
+    rows.chain(cols).collect()
+}
+
+/// The most tiles a beam entering from the edge energizes.
+pub fn max_energized_tiles(grid: &Grid<char>) -> usize {
+    edge_beams(grid)
+        .into_iter()
+        .map(|(pos, dir)| count_energized_tiles(grid, pos, dir))
+        .max()
+        .unwrap_or(0)
+}
+
+/// [`max_energized_tiles`], with the beams spread over all cores.
+#[cfg(feature = "parallel")]
+pub fn par_max_energized_tiles(grid: &Grid<char>) -> usize {
+    use rayon::prelude::*;
+
+    edge_beams(grid)
+        .into_par_iter()
+        .map(|(pos, dir)| count_energized_tiles(grid, pos, dir))
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input(".|.\n.#.\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidCell('#'));
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 2 })
+    );
+
+    let error = parse_input(".|.\n.\n").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Shape(ShapeError::RaggedRow {
+            expected: 3,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn test_generated() {
+    for seed in 0..10 {
+        let input = common::generate::input::<Day16>(seed, Some(20));
+        let grid = parse_input(&input).unwrap();
+        let energized = Day16::part1(&grid);
+        assert!((1..=400).contains(&energized));
+        assert!(Day16::part2(&grid) >= energized);
+        #[cfg(feature = "parallel")]
+        assert_eq!(par_max_energized_tiles(&grid), max_energized_tiles(&grid));
+    }
+}
@@ -0,0 +1,15 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day17::Day17;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day17>(0, None);
+
+    // Solving takes most of a second, so keep to the minimum number of samples
+    let mut group = c.benchmark_group("day17");
+    group.sample_size(10);
+    common::bench::solution::<Day17>(&mut group, input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+//! Made-up maps of heat loss, `size` blocks square.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day17;
+
+impl Generator for Day17 {
+    const REAL_SIZE: usize = 141;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Ultra crucibles need room for four blocks in a line before they can stop
+        let size = size.max(5);
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(char::from(b'1' + rng.below(9) as u8));
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
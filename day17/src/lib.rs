@@ -0,0 +1,153 @@
+use common::{
+    geometry::{Coord, Direction},
+    Maybe, Solution,
+};
+use grid::{Grid, ShapeError};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("expected a heat loss digit")]
+    InvalidDigit(char),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+pub fn parse_grid(input: &str) -> Result<Grid<i32>, ParseError> {
+    Grid::parse_with(input, |_, c| {
+        c.to_digit(10)
+            .map(|d| d as i32)
+            .ok_or(ParseErrorKind::InvalidDigit(c))
+    })
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+struct PathInfo {
+    direction: Direction,
+    sequential: i32,
+    current_position: Coord,
+}
+
+/// Finds the path from the top left to the bottom right that loses the least heat, and the heat
+/// it loses, unless the crucible can't get there.
+///
+/// Crucibles move at most 3 cells in a straight line, and `ultra` crucibles move at least 4 and at
+/// most 10 before turning.
+pub fn solve(grid: &Grid<i32>, ultra: bool) -> Option<(Vec<Coord>, i32)> {
+    let next_paths = |path_info: &PathInfo| {
+        // can't get out of bounds
+        // can't backtrack
+        // can't have more than 3 (or 10 if ultra) sequential cells
+        // can't turn before 4 sequential cells if ultra
+
+        let max_sequential = if ultra { 10 } else { 3 };
+        let min_sequential = if ultra { 4 } else { 0 };
+        let position = path_info.current_position;
+
+        Direction::ALL.map(|direction| {
+            let next_cell = position.step(direction);
+            let heat_loss = *grid.at(next_cell)?;
+            let straight = path_info.direction == direction;
+            let blocked = direction == path_info.direction.reverse()
+                || (straight && path_info.sequential == max_sequential)
+                || (!straight && path_info.sequential < min_sequential);
+            let next_path = PathInfo {
+                direction,
+                sequential: if straight {
+                    path_info.sequential + 1
+                } else {
+                    1
+                },
+                current_position: next_cell,
+            };
+            (!blocked).then_some((next_path, heat_loss))
+        })
+    };
+
+    let end = Coord::from_pos((grid.height() - 1, grid.width() - 1));
+    // Every cell left to cross loses at least this much heat
+    let min_heat_loss = grid.iter().copied().min().unwrap_or(0);
+    let start = PathInfo {
+        direction: Direction::East,
+        sequential: 0,
+        current_position: Coord::ORIGIN,
+    };
+
+    let search = search::astar(
+        start,
+        |path_info| next_paths(path_info).into_iter().flatten(),
+        |path_info| path_info.current_position.manhattan(end) as i32 * min_heat_loss,
+        |path_info| path_info.current_position == end,
+    );
+    let (path, cost) = search.goal_path()?;
+    let cells = path.iter().map(|path_info| path_info.current_position);
+    Some((cells.collect(), cost))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Grid<i32>;
+    type Error = ParseError;
+    type Answer1 = Maybe<i32>;
+    type Answer2 = Maybe<i32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Self::Answer1 {
+        solve(grid, false).map(|(_, heat_loss)| heat_loss).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Self::Answer2 {
+        solve(grid, true).map(|(_, heat_loss)| heat_loss).into()
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_grid("2413\n32x5\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidDigit('x'));
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 3 })
+    );
+
+    let error = parse_grid("2413\n325\n").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::Shape(ShapeError::RaggedRow {
+            expected: 4,
+            found: 3
+        })
+    );
+}
+
+#[test]
+fn test_generated() {
+    for seed in 0..10 {
+        let input = common::generate::input::<Day17>(seed, Some(15));
+        let grid = parse_grid(&input).unwrap();
+        for ultra in [false, true] {
+            let (path, heat_loss) = solve(&grid, ultra).unwrap();
+            // The heat of the starting block isn't lost
+            let lost = path[1..]
+                .iter()
+                .map(|&cell| grid.at(cell).unwrap())
+                .sum::<i32>();
+            assert_eq!(lost, heat_loss);
+        }
+    }
+}
+
+#[test]
+fn test_unreachable() {
+    // Normal crucibles can't go straight along a long row
+    let grid = parse_grid("12345\n").unwrap();
+    assert_eq!(solve(&grid, false), None);
+    assert_eq!(solve(&grid, true).map(|(_, heat_loss)| heat_loss), Some(14));
+}
@@ -0,0 +1,15 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day18::Day18;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day18>(0, None);
+
+    // Solving takes seconds, so keep to the minimum number of samples
+    let mut group = c.benchmark_group("day18");
+    group.sample_size(10);
+    common::bench::solution::<Day18>(&mut group, input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+//! Made-up dig plans of about `size` instructions, which never cross themselves, neither as
+//! written nor as encoded in the colors.
+
+use common::{
+    generate::{self, Generator, Rng},
+    geometry::{Coord, Direction},
+};
+
+use crate::Day18;
+
+/// Where each line of the loop's grid ends up, with gaps of up to `max_gap` between them.
+fn stretch(rng: &mut Rng, lines: usize, max_gap: i64) -> Vec<i64> {
+    let mut position = 0;
+    (0..lines)
+        .map(|_| {
+            position += rng.between(1, max_gap);
+            position
+        })
+        .collect()
+}
+
+impl Generator for Day18 {
+    const REAL_SIZE: usize = 784;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // A loop around a grid of cells stays simple however far apart the rows and columns
+        // are pushed, as long as they stay in order
+        let blocks = (size as f64).sqrt().max(1.0) as usize;
+        let mut cells = generate::tree_loop(rng, blocks, blocks, size / 2);
+        let direction = |from: Coord, to: Coord| {
+            *Direction::ALL
+                .iter()
+                .find(|direction| direction.offset() == to - from)
+                .unwrap()
+        };
+        let heading = |cells: &[Coord], i: usize| direction(cells[i], cells[(i + 1) % cells.len()]);
+
+        // Start on a corner, so no line of the plan is cut in two
+        let corner = (0..cells.len())
+            .find(|&i| heading(&cells, i) != heading(&cells, (i + cells.len() - 1) % cells.len()))
+            .unwrap();
+        cells.rotate_left(corner);
+
+        // The colors encode distances in five hex digits
+        let lines = 2 * blocks;
+        let max_gap = (0xfffff / lines as i64).min(20_000);
+        let (rows, cols) = (stretch(rng, lines, 10), stretch(rng, lines, 10));
+        let (color_rows, color_cols) = (stretch(rng, lines, max_gap), stretch(rng, lines, max_gap));
+
+        let mut input = String::new();
+        let mut from = 0;
+        while from < cells.len() {
+            let direction = heading(&cells, from);
+            let mut to = from + 1;
+            while to < cells.len() && heading(&cells, to) == direction {
+                to += 1;
+            }
+
+            let (a, b) = (cells[from], cells[to % cells.len()]);
+            let distance = |rows: &[i64], cols: &[i64]| {
+                let (a, b) = (a.to_pos().unwrap(), b.to_pos().unwrap());
+                rows[a.0].abs_diff(rows[b.0]) + cols[a.1].abs_diff(cols[b.1])
+            };
+            let (letter, digit) = match direction {
+                Direction::East => ('R', 0),
+                Direction::South => ('D', 1),
+                Direction::West => ('L', 2),
+                Direction::North => ('U', 3),
+            };
+            input.push_str(&format!(
+                "{letter} {} (#{:05x}{digit})\n",
+                distance(&rows, &cols),
+                distance(&color_rows, &color_cols)
+            ));
+            from = to;
+        }
+        input
+    }
+}
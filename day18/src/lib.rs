@@ -0,0 +1,401 @@
+use std::ops::{Add, Div};
+
+use common::{
+    geometry::{Coord, Direction},
+    Solution,
+};
+use nom::{
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{anychar, digit1, one_of, space1},
+    combinator::{map, map_res, verify},
+    sequence::Tuple,
+    IResult,
+};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("empty dig plan")]
+    Empty,
+    #[error("expected `<U|D|L|R> <distance> (#<rrggbb>)`")]
+    InvalidInstruction,
+    #[error("unexpected characters after instruction")]
+    TrailingCharacters,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+/// The longest a written instruction digs, as long as the colors can encode, which keeps the
+/// area within an `i64` for any plan that fits in memory.
+pub const MAX_DISTANCE: i32 = 0xfffff;
+
+#[derive(Debug, Copy, Clone)]
+struct Instruction {
+    direction: Direction,
+    distance: i32,
+}
+
+fn from_hex(input: &str) -> Result<u8, std::num::ParseIntError> {
+    u8::from_str_radix(input, 16)
+}
+
+fn is_hex_digit(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+fn hex_primary(input: &str) -> IResult<&str, u8> {
+    nom::Parser::parse(
+        &mut map_res(take_while_m_n(2, 2, is_hex_digit), from_hex),
+        input,
+    )
+}
+
+fn hex_color(input: &str) -> IResult<&str, (u8, u8, u8)> {
+    let (input, _) = tag("#")(input)?;
+    let (input, (red, green, blue)) = (hex_primary, hex_primary, hex_primary).parse(input)?;
+
+    Ok((input, (red, green, blue)))
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (input, direction) = map(one_of("UDLR"), |c| match c {
+        'U' => Direction::North,
+        'D' => Direction::South,
+        'L' => Direction::West,
+        'R' => Direction::East,
+        _ => unreachable!(),
+    })(input)?;
+    let (input, _) = space1(input)?;
+    let (input, distance) = verify(map_res(digit1, str::parse::<i32>), |&distance| {
+        distance <= MAX_DISTANCE
+    })(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("(")(input)?;
+    // The color only matters read as an instruction of its own
+    let (input, _) = hex_color(input)?;
+    let (input, _) = tag(")")(input)?;
+
+    let instruction = Instruction {
+        direction,
+        distance,
+    };
+
+    Ok((input, instruction))
+}
+
+fn parse_hex_five_digits(input: &str) -> IResult<&str, usize> {
+    let (input, _) = tag("#")(input)?;
+    nom::Parser::parse(
+        &mut map_res(take_while_m_n(5, 5, is_hex_digit), |s: &str| {
+            usize::from_str_radix(s, 16)
+        }),
+        input,
+    )
+}
+
+fn parse_direction_from_hex(input: &str) -> IResult<&str, Direction> {
+    // 0 means R
+    // 1 means D
+    // 2 means L
+    // 3 means U
+
+    let (input, direction) = map(
+        nom::branch::alt((tag("0"), tag("1"), tag("2"), tag("3"))),
+        |s: &str| match s {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            _ => unreachable!(),
+        },
+    )(input)?;
+
+    Ok((input, direction))
+}
+
+fn parse_instruction_second_part(input: &str) -> IResult<&str, Instruction> {
+    let (input, _) = anychar(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = digit1(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("(")(input)?;
+
+    let (input, distance) = parse_hex_five_digits(input)?;
+    let (input, direction) = parse_direction_from_hex(input)?;
+
+    let (input, _) = tag(")")(input)?;
+
+    let instruction = Instruction {
+        direction,
+        distance: distance as i32,
+    };
+
+    Ok((input, instruction))
+}
+
+fn parse_lines(
+    input_str: &str,
+    parse_line: impl Fn(&str) -> IResult<&str, Instruction>,
+) -> Result<Vec<Instruction>, ParseError> {
+    if input_str.trim().is_empty() {
+        return Err(ParseError::whole_input(ParseErrorKind::Empty));
+    }
+
+    let lines = input_str.lines();
+
+    let instructions: Result<Vec<_>, _> = lines
+        .map(|line| {
+            let (rest, instruction) = parse_line(line).map_err(|e| match e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => {
+                    ParseError::new(e.input, ParseErrorKind::InvalidInstruction)
+                }
+                nom::Err::Incomplete(_) => {
+                    ParseError::new(line, ParseErrorKind::InvalidInstruction)
+                }
+            })?;
+            if !rest.is_empty() {
+                return Err(ParseError::new(rest, ParseErrorKind::TrailingCharacters));
+            }
+            Ok(instruction)
+        })
+        .collect();
+    instructions.map_err(|e| e.locate(input_str))
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input_str, parse_instruction)
+}
+
+fn parse_input_second_part(input_str: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input_str, parse_instruction_second_part)
+}
+
+/// Every hole dug, one at a time, which is too slow for the real plans but easy to check.
+#[cfg(test)]
+fn run_instructions(instructions: &[Instruction]) -> Vec<Coord> {
+    let mut holes_dug = Vec::new();
+    let mut current_position = Coord::ORIGIN;
+    for Instruction {
+        direction,
+        distance,
+    } in instructions.iter().copied()
+    {
+        for _ in 0..distance {
+            current_position = current_position.step(direction);
+            holes_dug.push(current_position);
+        }
+    }
+    holes_dug
+}
+
+/// Counts the holes dug by filling in the lagoon, as everything that the trench cuts off from
+/// the outside.
+#[cfg(test)]
+fn flood_fill(instructions: &[Instruction]) -> i64 {
+    let trench = run_instructions(instructions)
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>();
+    let rows = trench.iter().map(|cell| cell.row);
+    let cols = trench.iter().map(|cell| cell.col);
+    // One cell of margin keeps the outside in one piece
+    let (top, bottom) = (rows.clone().min().unwrap() - 1, rows.max().unwrap() + 1);
+    let (left, right) = (cols.clone().min().unwrap() - 1, cols.max().unwrap() + 1);
+
+    let outside = search::bfs(Coord::new(top, left), |&cell| {
+        Direction::ALL
+            .map(|direction| cell.step(direction))
+            .into_iter()
+            .filter(|next| (top..=bottom).contains(&next.row) && (left..=right).contains(&next.col))
+            .filter(|next| !trench.contains(next))
+            .collect::<Vec<_>>()
+    });
+    (bottom - top + 1) * (right - left + 1) - outside.distances.len() as i64
+}
+
+/// Where each instruction leaves the digger, from where it starts.
+fn run_instructions_corners(instructions: &[Instruction]) -> Vec<Coord> {
+    let mut corners = vec![Coord::ORIGIN];
+    for instruction in instructions {
+        let corner = corners
+            .last()
+            .unwrap()
+            .step_n(instruction.direction, instruction.distance as i64);
+        corners.push(corner);
+    }
+    corners
+}
+
+fn shoelace_formula(points: &[Coord]) -> i64 {
+    let len = points.len();
+
+    let (area, perimeter) =
+        points
+            .iter()
+            .enumerate()
+            .fold((0i64, 0i64), |(sum, perimeter), (i, p1)| {
+                let l = (i + 1) % len;
+                let p2 = points[l];
+
+                let new_perimeter = perimeter + p1.manhattan(p2) as i64;
+                let new_area = sum + ((p1.row * p2.col) - (p1.col * p2.row));
+
+                (new_area, new_perimeter)
+            });
+
+    area.abs().add(perimeter).div(2).add(1)
+}
+
+/// The dig plan, read as written and as encoded in the colors.
+pub struct DigPlan {
+    instructions: Vec<Instruction>,
+    color_instructions: Vec<Instruction>,
+}
+
+fn holes_dug(instructions: &[Instruction]) -> i64 {
+    // The corners are enough for the area, and the perimeter is just as long along them
+    shoelace_formula(&run_instructions_corners(instructions))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = DigPlan;
+    type Error = ParseError;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        Ok(DigPlan {
+            instructions: parse_input(input)?,
+            color_instructions: parse_input_second_part(input)?,
+        })
+    }
+
+    fn part1(plan: &Self::Parsed<'_>) -> Self::Answer1 {
+        holes_dug(&plan.instructions)
+    }
+
+    fn part2(plan: &Self::Parsed<'_>) -> Self::Answer2 {
+        holes_dug(&plan.color_instructions)
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("R 6 (#70c710)\nX 5 (#0dc571)\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidInstruction);
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 1 })
+    );
+
+    let error = parse_input_second_part("R 6 (#70c710)\nD 5 (#0dc57)\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidInstruction);
+    assert_eq!(
+        error.location,
+        Some(common::Location {
+            line: 2,
+            column: 12
+        })
+    );
+
+    let error = parse_input("R 6 (#70c710)\nD 1048576 (#0dc571)\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidInstruction);
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 3 })
+    );
+
+    let error = parse_input("R 6 (#70c710) 3\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::TrailingCharacters);
+    assert_eq!(
+        error.location,
+        Some(common::Location {
+            line: 1,
+            column: 14
+        })
+    );
+}
+
+#[test]
+fn test_generated() {
+    for seed in 0..10 {
+        let input = common::generate::input::<Day18>(seed, Some(50));
+        let plan = Day18::parse(&input).unwrap();
+        for instructions in [&plan.instructions, &plan.color_instructions] {
+            let dug = run_instructions(instructions);
+            assert_eq!(dug.last(), Some(&Coord::ORIGIN));
+            assert!(holes_dug(instructions) >= dug.len() as i64);
+
+            let mut points = dug.clone();
+            points.push(dug[0]);
+            assert_eq!(shoelace_formula(&points), holes_dug(instructions));
+        }
+    }
+}
+
+/// The outline of a lagoon made of blocks of columns side by side, each overlapping the last.
+///
+/// Its lengths are doubled, so that no two stretches of trench run right next to each other and
+/// cut off the ground between them.
+#[cfg(test)]
+fn lagoon_outline() -> impl proptest::strategy::Strategy<Value = Vec<Instruction>> {
+    use proptest::strategy::Strategy;
+
+    proptest::collection::vec((1..4i64, 0..6i64, 1..6i64), 1..8).prop_map(|blocks| {
+        let mut spans: Vec<(i64, i64, i64)> = vec![];
+        for (width, top, height) in blocks {
+            let (top, bottom) = match spans.last() {
+                None => (top, top + height),
+                Some(&(_, last_top, last_bottom)) => {
+                    let top = top.min(last_bottom - 1);
+                    (top, (top + height).max(last_top + 1))
+                }
+            };
+            spans.push((width, top, bottom));
+        }
+
+        // Along the tops from left to right, then back along the bottoms
+        let mut corners = vec![];
+        let mut col = 0;
+        for &(width, top, _) in &spans {
+            corners.push(Coord::new(top, col));
+            corners.push(Coord::new(top, col + width));
+            col += width;
+        }
+        for &(width, _, bottom) in spans.iter().rev() {
+            corners.push(Coord::new(bottom, col));
+            corners.push(Coord::new(bottom, col - width));
+            col -= width;
+        }
+
+        let start = corners[0];
+        corners.push(start);
+        corners
+            .windows(2)
+            .filter(|w| w[0] != w[1])
+            .map(|w| {
+                let step = w[1] - w[0];
+                let direction = match (step.row.signum(), step.col.signum()) {
+                    (1, _) => Direction::South,
+                    (-1, _) => Direction::North,
+                    (_, 1) => Direction::East,
+                    _ => Direction::West,
+                };
+                Instruction {
+                    direction,
+                    distance: 2 * w[0].manhattan(w[1]) as i32,
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_shoelace_matches_flood_fill(instructions in lagoon_outline()) {
+        proptest::prop_assert_eq!(holes_dug(&instructions), flood_fill(&instructions));
+    }
+}
@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day19::Day19;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day19>(0, None);
+    common::bench::solution::<Day19>(&mut c.benchmark_group("day19"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
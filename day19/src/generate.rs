@@ -0,0 +1,61 @@
+//! Made-up systems of `size` workflows, which send parts down a tree from `in`, along with
+//! some parts to sort.
+
+use std::collections::{HashSet, VecDeque};
+
+use common::generate::{Generator, Rng};
+
+use crate::Day19;
+
+impl Generator for Day19 {
+    const REAL_SIZE: usize = 550;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut used = HashSet::from(["in".to_owned()]);
+        let mut new_name = |rng: &mut Rng| loop {
+            let name = (0..rng.between(2, 3))
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect::<String>();
+            if used.insert(name.clone()) {
+                return name;
+            }
+        };
+
+        // Every workflow sends parts to new workflows until there are enough of them
+        let mut workflows = vec![];
+        let mut queue = VecDeque::from(["in".to_owned()]);
+        let mut created = 1;
+        while let Some(name) = queue.pop_front() {
+            let mut target = |rng: &mut Rng| {
+                if created < size && rng.chance(0.6) {
+                    created += 1;
+                    let name = new_name(rng);
+                    queue.push_back(name.clone());
+                    name
+                } else {
+                    rng.choose(&["A", "R"]).to_string()
+                }
+            };
+
+            let mut rules = (0..rng.between(1, 3))
+                .map(|_| {
+                    let field = rng.choose(&['x', 'm', 'a', 's']);
+                    let operator = rng.choose(&['<', '>']);
+                    let value = rng.between(1, 4000);
+                    format!("{field}{operator}{value}:{}", target(rng))
+                })
+                .collect::<Vec<_>>();
+            rules.push(target(rng));
+            workflows.push(format!("{name}{{{}}}", rules.join(",")));
+        }
+        rng.shuffle(&mut workflows);
+
+        let mut input = workflows.join("\n");
+        input.push_str("\n\n");
+        for _ in 0..(size * 2 / 5).max(1) {
+            let [x, m, a, s] = [(); 4].map(|_| rng.between(1, 4000));
+            input.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+        }
+        input
+    }
+}
@@ -0,0 +1,448 @@
+use std::{
+    collections::{HashMap, HashSet},
+    num::ParseIntError,
+};
+
+use common::{interval::IntervalSet, Solution};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected `<name>{{<rules>}}`")]
+    InvalidWorkflow,
+    #[error("expected `{{x=<n>,m=<n>,a=<n>,s=<n>}}`")]
+    InvalidPart,
+    #[error("expected one of `x`, `m`, `a`, `s`")]
+    UnknownField,
+    #[error("expected `<` or `>`")]
+    UnknownOperator,
+    #[error("last rule of a workflow must be unconditional")]
+    MissingFallback,
+    #[error("reference to undefined workflow")]
+    UnknownWorkflow,
+    #[error("missing `in` workflow")]
+    MissingInWorkflow,
+    #[error("workflow sends parts round in a loop")]
+    WorkflowCycle,
+    #[error("ratings must be between 0 and {MAX_RATING}")]
+    RatingOutOfRange,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+/// The highest rating a part can have, in any category.
+pub const MAX_RATING: isize = 4000;
+
+fn parse_number(token: &str) -> Result<isize, ParseError> {
+    let number = token
+        .parse()
+        .map_err(|e| ParseError::new(token, ParseErrorKind::InvalidNumber(e)))?;
+    if !(0..=MAX_RATING).contains(&number) {
+        return Err(ParseError::new(token, ParseErrorKind::RatingOutOfRange));
+    }
+    Ok(number)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Condition {
+    field: char,    // 'x', 'm', 'a', 's'
+    operator: char, // '<', '>'
+    value: isize,
+}
+
+fn parse_condition(input_str: &str) -> Result<Condition, ParseError> {
+    let field = match input_str.get(..1) {
+        Some(field @ ("x" | "m" | "a" | "s")) => field,
+        _ => return Err(ParseError::new(input_str, ParseErrorKind::UnknownField)),
+    };
+    let operator = match input_str.get(1..2) {
+        Some(operator @ ("<" | ">")) => operator,
+        _ => {
+            return Err(ParseError::new(
+                &input_str[1..],
+                ParseErrorKind::UnknownOperator,
+            ))
+        }
+    };
+    let value = parse_number(&input_str[2..])?;
+    Ok(Condition {
+        field: field.as_bytes()[0] as char,
+        operator: operator.as_bytes()[0] as char,
+        value,
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Rule {
+    condition: Option<Condition>,
+    then: String,
+}
+
+fn parse_rule(input_str: &str) -> Result<Rule, ParseError> {
+    let (condition, then) = match input_str.split_once(':') {
+        Some((condition, then)) => (Some(parse_condition(condition)?), then.to_owned()),
+        None => (None, input_str.to_owned()),
+    };
+    Ok(Rule { condition, then })
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+fn parse_workflow(input_line: &str) -> Result<Workflow, ParseError> {
+    let (name, rules_str) = input_line
+        .strip_suffix('}')
+        .and_then(|line| line.split_once('{'))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| ParseError::new(input_line, ParseErrorKind::InvalidWorkflow))?;
+    let rules = rules_str
+        .split(',')
+        .map(parse_rule)
+        .collect::<Result<Vec<_>, _>>()?;
+    if rules.last().is_some_and(|rule| rule.condition.is_some()) {
+        return Err(ParseError::new(rules_str, ParseErrorKind::MissingFallback));
+    }
+    Ok(Workflow {
+        name: name.to_string(),
+        rules,
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Part {
+    x: isize,
+    m: isize,
+    a: isize,
+    s: isize,
+}
+
+fn parse_part(input_line: &str) -> Result<Part, ParseError> {
+    let parts = input_line
+        .strip_prefix('{')
+        .and_then(|line| line.strip_suffix('}'))
+        .ok_or_else(|| ParseError::new(input_line, ParseErrorKind::InvalidPart))?
+        .split(',');
+
+    let mut part = Part {
+        x: 0,
+        m: 0,
+        a: 0,
+        s: 0,
+    };
+
+    for part_str in parts {
+        let (field, value) = part_str
+            .split_once('=')
+            .ok_or_else(|| ParseError::new(part_str, ParseErrorKind::InvalidPart))?;
+        let value = parse_number(value)?;
+        match field {
+            "x" => part.x = value,
+            "m" => part.m = value,
+            "a" => part.a = value,
+            "s" => part.s = value,
+            _ => return Err(ParseError::new(field, ParseErrorKind::UnknownField)),
+        }
+    }
+
+    Ok(part)
+}
+
+fn part_matches(workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
+    fn part_matches_recursive(
+        workflows: &HashMap<String, Workflow>,
+        workflow: &Workflow,
+        part: &Part,
+    ) -> bool {
+        for rule in &workflow.rules {
+            let mut meets_condition = true;
+            if let Some(condition) = &rule.condition {
+                let value = match condition.field {
+                    'x' => part.x,
+                    'm' => part.m,
+                    'a' => part.a,
+                    's' => part.s,
+                    _ => panic!("Unknown field: {}", condition.field),
+                };
+                meets_condition = match condition.operator {
+                    '<' => value < condition.value,
+                    '>' => value > condition.value,
+                    _ => panic!("Unknown operator: {}", condition.operator),
+                };
+            }
+
+            if meets_condition {
+                match rule.then.as_str() {
+                    "A" => return true,
+                    "R" => return false,
+                    name => {
+                        return part_matches_recursive(
+                            workflows,
+                            workflows.get(name).unwrap(),
+                            part,
+                        );
+                    }
+                }
+            }
+        }
+        panic!("No rule met the condition");
+    }
+
+    part_matches_recursive(workflows, workflows.get("in").unwrap(), part)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct PartRange {
+    x: IntervalSet<isize>,
+    m: IntervalSet<isize>,
+    a: IntervalSet<isize>,
+    s: IntervalSet<isize>,
+}
+
+impl PartRange {
+    fn new() -> Self {
+        PartRange {
+            x: IntervalSet::from(1..4001),
+            m: IntervalSet::from(1..4001),
+            a: IntervalSet::from(1..4001),
+            s: IntervalSet::from(1..4001),
+        }
+    }
+
+    fn field_mut(&mut self, letter: char) -> &mut IntervalSet<isize> {
+        match letter {
+            'x' => &mut self.x,
+            'm' => &mut self.m,
+            'a' => &mut self.a,
+            's' => &mut self.s,
+            _ => panic!("Unknown letter: {}", letter),
+        }
+    }
+
+    fn bisect(self, letter: char, at: isize) -> (Self, Self) {
+        let mut right = self.clone();
+        let mut left = self;
+        let (below, above) = left.field_mut(letter).split_at(at);
+        *left.field_mut(letter) = below;
+        *right.field_mut(letter) = above;
+        (left, right)
+    }
+
+    fn bisect_comparator(self, comparator: char, letter: char, at: isize) -> (Self, Self) {
+        match comparator {
+            '<' => self.bisect(letter, at),
+            '>' => {
+                let (l, r) = self.bisect(letter, at + 1);
+                (r, l)
+            }
+            _ => panic!("Unknown comparator: {}", comparator),
+        }
+    }
+
+    fn cardinality(&self) -> usize {
+        [&self.x, &self.m, &self.a, &self.s]
+            .iter()
+            .map(|set| set.cardinality() as usize)
+            .product()
+    }
+}
+
+fn part_ranges_cardinality(workflows: &HashMap<String, Workflow>, part_range: PartRange) -> usize {
+    fn part_ranges_cardinality_recursive(
+        workflows: &HashMap<String, Workflow>,
+        workflow: &Workflow,
+        mut part_range: PartRange,
+    ) -> usize {
+        let cardinality_recursive = |part_range: PartRange, rule: &Rule| match rule.then.as_str() {
+            "A" => part_range.cardinality(),
+            "R" => 0,
+            name => part_ranges_cardinality_recursive(
+                workflows,
+                workflows.get(name).unwrap(),
+                part_range,
+            ),
+        };
+
+        let mut cardinality = 0;
+        for rule in &workflow.rules {
+            if let Some(ref condition) = rule.condition {
+                let (matches, doesnt) = part_range.bisect_comparator(
+                    condition.operator,
+                    condition.field,
+                    condition.value,
+                );
+                cardinality += cardinality_recursive(matches, rule);
+                part_range = doesnt;
+            } else {
+                return cardinality + cardinality_recursive(part_range, rule);
+            }
+        }
+        cardinality
+    }
+    part_ranges_cardinality_recursive(workflows, workflows.get("in").unwrap(), part_range)
+}
+
+/// Whether following the rules of `start` can lead back to it.
+fn is_on_cycle(workflows: &HashMap<String, Workflow>, start: &str) -> bool {
+    let successors = |name: &str| {
+        workflows[name]
+            .rules
+            .iter()
+            .map(|rule| rule.then.as_str())
+            .filter(|then| workflows.contains_key(*then))
+            .collect::<Vec<_>>()
+    };
+    let mut seen = HashSet::new();
+    let mut stack = successors(start);
+    while let Some(name) = stack.pop() {
+        if name == start {
+            return true;
+        }
+        if seen.insert(name) {
+            stack.extend(successors(name));
+        }
+    }
+    false
+}
+
+#[derive(Debug)]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+fn parse_input(input_str: &str) -> Result<System, ParseError> {
+    parse_system(input_str).map_err(|e| e.locate(input_str))
+}
+
+fn parse_system(input_str: &str) -> Result<System, ParseError> {
+    let mut lines = input_str.lines();
+    let workflow_lines = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    let workflows: HashMap<String, Workflow> = workflow_lines
+        .iter()
+        .map(|line| parse_workflow(line))
+        .map(|workflow| workflow.map(|workflow| (workflow.name.clone(), workflow)))
+        .collect::<Result<_, _>>()?;
+
+    if !workflows.contains_key("in") {
+        return Err(ParseError::whole_input(ParseErrorKind::MissingInWorkflow));
+    }
+
+    // Point at the first rule (in input order) sending parts to a missing workflow
+    for line in &workflow_lines {
+        let rules = &line[line.find('{').unwrap() + 1..line.len() - 1];
+        let unknown = rules
+            .split(',')
+            .map(|rule| rule.split_once(':').map_or(rule, |(_, then)| then))
+            .find(|then| !matches!(*then, "A" | "R") && !workflows.contains_key(*then));
+        if let Some(unknown) = unknown {
+            return Err(ParseError::new(unknown, ParseErrorKind::UnknownWorkflow));
+        }
+    }
+
+    // Parts would never leave a loop, and neither would the search through the workflows
+    for line in workflow_lines {
+        let name = &line[..line.find('{').unwrap()];
+        if is_on_cycle(&workflows, name) {
+            return Err(ParseError::new(name, ParseErrorKind::WorkflowCycle));
+        }
+    }
+
+    let parts = lines.map(parse_part).collect::<Result<Vec<_>, _>>()?;
+
+    Ok(System { workflows, parts })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = System;
+    type Error = ParseError;
+    type Answer1 = isize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(system: &Self::Parsed<'_>) -> Self::Answer1 {
+        system
+            .parts
+            .iter()
+            .filter(|part| part_matches(&system.workflows, part))
+            .map(|part| part.x + part.m + part.a + part.s)
+            .sum::<isize>()
+    }
+
+    fn part2(system: &Self::Parsed<'_>) -> Self::Answer2 {
+        part_ranges_cardinality(&system.workflows, PartRange::new())
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("in{x<10:A,R}\n\n{x=1,m=2,q=3,s=4}\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::UnknownField);
+    assert_eq!(
+        error.location,
+        Some(common::Location {
+            line: 3,
+            column: 10
+        })
+    );
+
+    let error = parse_input("in{x<10:px,R}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::UnknownWorkflow);
+    assert_eq!(error.token, "px");
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 1, column: 9 })
+    );
+
+    let error = parse_input("in{x=10:A,R}\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::UnknownOperator);
+
+    let error = parse_input("in{x<10:A}\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::MissingFallback);
+
+    let error = parse_input("in{x<10:A,R}\n\n{x=1,m=4001,a=3,s=4}\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::RatingOutOfRange);
+    assert_eq!(error.token, "4001");
+
+    let error = parse_input("in{x<10:A,px}\npx{m>5:qs,R}\nqs{px}\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::WorkflowCycle);
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 1 })
+    );
+}
+
+#[test]
+fn test_generated() {
+    // A part is a range of a single part, which is accepted exactly when the part is
+    for seed in 0..10 {
+        let input = common::generate::input::<Day19>(seed, Some(30));
+        let system = parse_input(&input).unwrap();
+        for part in &system.parts {
+            let point = |value: isize| IntervalSet::from(value..value + 1);
+            let range = PartRange {
+                x: point(part.x),
+                m: point(part.m),
+                a: point(part.a),
+                s: point(part.s),
+            };
+            assert_eq!(
+                part_ranges_cardinality(&system.workflows, range),
+                part_matches(&system.workflows, part) as usize
+            );
+        }
+    }
+}
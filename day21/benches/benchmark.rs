@@ -0,0 +1,11 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day21::Day21;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input_str = &common::generate::input::<Day21>(0, None);
+    common::bench::solution::<Day21>(&mut c.benchmark_group("day21"), input_str);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
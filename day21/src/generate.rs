@@ -0,0 +1,30 @@
+//! Made-up gardens, `size` plots square with the start in the middle.
+
+use common::generate::{Generator, Rng};
+
+use crate::Day21;
+
+impl Generator for Day21 {
+    const REAL_SIZE: usize = 131;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let middle = size / 2;
+        let mut input = String::new();
+        for row in 0..size {
+            for col in 0..size {
+                // Like in the real gardens, the start's row and column are clear of rocks
+                let tile = if (row, col) == (middle, middle) {
+                    'S'
+                } else if row != middle && col != middle && rng.chance(0.12) {
+                    '#'
+                } else {
+                    '.'
+                };
+                input.push(tile);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use common::{
+    geometry::{Coord, Direction},
+    Solution, Unsolved,
+};
+use grid::{Grid, ShapeError};
+
+mod generate;
+
+#[derive(Copy, Clone, Debug)]
+enum Tile {
+    Empty,
+    Blocked,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Empty => write!(f, "."),
+            Tile::Blocked => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    start: Coord,
+    grid: Grid<Tile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+    #[error("expected `.`, `#` or `S`")]
+    InvalidTile(char),
+    #[error("missing start tile `S`")]
+    MissingStart,
+    #[error("more than one start tile `S`")]
+    DuplicateStart,
+}
+
+pub type ParseError = common::ParseError<ParseErrorKind>;
+
+fn parse_input(input_str: &str) -> Result<Garden, ParseError> {
+    let mut starting_pos = None;
+    let grid = Grid::parse_with(input_str, |pos, c| match c {
+        '.' => Ok(Tile::Empty),
+        '#' => Ok(Tile::Blocked),
+        'S' => match starting_pos.replace(Coord::from_pos(pos)) {
+            Some(_) => Err(ParseErrorKind::DuplicateStart),
+            None => Ok(Tile::Empty),
+        },
+        _ => Err(ParseErrorKind::InvalidTile(c)),
+    })?;
+    let start =
+        starting_pos.ok_or_else(|| ParseError::whole_input(ParseErrorKind::MissingStart))?;
+    Ok(Garden { start, grid })
+}
+
+/// Counts the plots that can be the last of a walk of exactly `steps` steps.
+///
+/// Walks can go back and forth between two plots, so a plot is reachable in exactly `steps` if
+/// it's reachable in fewer with the same parity.
+fn part_1(grid: &Grid<Tile>, from: Coord, steps: usize) -> usize {
+    let search = search::bfs(from, |&coord| {
+        Direction::ALL
+            .map(|direction| coord.step(direction))
+            .into_iter()
+            .filter(|&next| matches!(grid.at(next), Some(Tile::Empty)))
+    });
+    search
+        .distances
+        .values()
+        .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+        .count()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = Garden;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(garden: &Self::Parsed<'_>) -> Self::Answer1 {
+        part_1(&garden.grid, garden.start, 64)
+    }
+
+    fn part2(_garden: &Self::Parsed<'_>) -> Self::Answer2 {
+        Unsolved
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = parse_input("...\n.S.\n.x.\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::InvalidTile('x'));
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 3, column: 2 })
+    );
+
+    let error = parse_input("S..\n.S.\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::DuplicateStart);
+    assert_eq!(
+        error.location,
+        Some(common::Location { line: 2, column: 2 })
+    );
+
+    let error = parse_input("...\n").unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::MissingStart);
+}
+
+#[test]
+fn test_generated() {
+    // Whatever could be reached in some steps can be reached again two steps later
+    for seed in 0..10 {
+        let input = common::generate::input::<Day21>(seed, Some(21));
+        let garden = parse_input(&input).unwrap();
+        assert_eq!(part_1(&garden.grid, garden.start, 0), 1);
+        for steps in 0..20 {
+            assert!(
+                part_1(&garden.grid, garden.start, steps + 2)
+                    >= part_1(&garden.grid, garden.start, steps)
+            );
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! A rectangular grid of cells, for the days whose input is a 2D map.
+//!
+//! Cells are addressed by `(row, column)`, both counted from 0 at the top left.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::{
+    geometry::{Coord, Direction, Direction8},
+    ParseError,
+};
+
+/// Position of a cell, as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Why the lines of an input don't make up a grid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ShapeError {
+    #[error("empty grid")]
+    Empty,
+    #[error("expected {expected} columns, found {found}")]
+    RaggedRow { expected: usize, found: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+
+    /// Builds a grid by calling `f` with the position of every cell, in row-major order.
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Parses one cell per character and one row per line, converting cells with `f`.
+    ///
+    /// `f` gets the position of the cell too, and the error it returns is reported at that
+    /// character of the input.
+    pub fn parse_with<K>(
+        input: &str,
+        mut f: impl FnMut(Pos, char) -> Result<T, K>,
+    ) -> Result<Self, ParseError<K>>
+    where
+        K: From<ShapeError>,
+    {
+        let mut cells = vec![];
+        let mut height = 0;
+        let mut width = None;
+        for (row, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (col, (i, c)) in line.char_indices().enumerate() {
+                let cell = f((row, col), c).map_err(|kind| {
+                    ParseError::new(&line[i..i + c.len_utf8()], kind).locate(input)
+                })?;
+                cells.push(cell);
+            }
+
+            let found = cells.len() - row_start;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                let kind = ShapeError::RaggedRow { expected, found };
+                return Err(ParseError::new(line, kind.into()).locate(input));
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                cells,
+                height,
+                width,
+            }),
+            _ => Err(ParseError::whole_input(ShapeError::Empty.into())),
+        }
+    }
+
+    /// Parses cells with their [`TryFrom<char>`] implementation.
+    pub fn parse<K>(input: &str) -> Result<Self, ParseError<K>>
+    where
+        T: TryFrom<char>,
+        K: From<ShapeError> + From<T::Error>,
+    {
+        Self::parse_with(input, |_, c| T::try_from(c).map_err(K::from))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// Looks up a cell by signed coordinates, which are outside the grid when negative.
+    pub fn get_signed<I: TryInto<usize>>(&self, row: I, col: I) -> Option<&T> {
+        self.get((row.try_into().ok()?, col.try_into().ok()?))
+    }
+
+    pub fn at(&self, coord: Coord) -> Option<&T> {
+        self.get_signed(coord.row, coord.col)
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// Steps from `pos` towards `direction`, unless that leaves the grid.
+    pub fn step(&self, pos: Pos, direction: impl Into<Direction8>) -> Option<Pos> {
+        let pos = Coord::from_pos(pos).step(direction).to_pos()?;
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Positions of the cells north, east, south and west of `pos` that are in the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Positions of the cells surrounding `pos`, diagonals included, clockwise from north.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `col`, from top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {col} out of bounds");
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn column_mut(
+        &mut self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        assert!(col < self.width, "column {col} out of bounds");
+        self.cells[col..].iter_mut().step_by(self.width)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell along with its position, in row-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    /// Position of the first cell, in row-major order, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(&mut predicate)?;
+        Some((i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Location;
+
+    #[derive(Debug, PartialEq, Eq, thiserror::Error)]
+    enum Kind {
+        #[error(transparent)]
+        Shape(#[from] ShapeError),
+        #[error("expected a digit")]
+        NotADigit,
+        #[error("expected `.` or `#`")]
+        NotATile,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = Kind;
+
+        fn try_from(c: char) -> Result<Self, Kind> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(Kind::NotATile),
+            }
+        }
+    }
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError<Kind>> {
+        Grid::parse_with(input, |_, c| c.to_digit(10).ok_or(Kind::NotADigit))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\r\n456\r\n").unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let grid = Grid::<Tile>::parse::<Kind>("..\n#.").unwrap();
+        assert_eq!(grid.position(|t| *t == Tile::Wall), Some((1, 0)));
+        let error = Grid::<Tile>::parse::<Kind>("..\n.?").unwrap_err();
+        assert_eq!(error.kind, Kind::NotATile);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = digits("123\n4x6\n").unwrap_err();
+        assert_eq!(error.kind, Kind::NotADigit);
+        assert_eq!(error.token, "x");
+        assert_eq!(error.location, Some(Location { line: 2, column: 2 }));
+
+        let error = digits("123\n45\n").unwrap_err();
+        assert_eq!(
+            error.kind,
+            Kind::Shape(ShapeError::RaggedRow {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(error.location, Some(Location { line: 2, column: 1 }));
+
+        let error = digits("").unwrap_err();
+        assert_eq!(error.kind, Kind::Shape(ShapeError::Empty));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.get((1, 2)), Some(&6));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_signed(0, 1), Some(&2));
+        assert_eq!(grid.get_signed(-1, 1), None);
+        assert_eq!(grid.get_signed(1i64, 3), None);
+        assert_eq!(grid.at(Coord::new(1, 0)), Some(&4));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((0, 2)).collect::<Vec<_>>(),
+            vec![(1, 2), (1, 1), (0, 1)]
+        );
+        assert_eq!(grid.step((2, 1), Direction::East), Some((2, 2)));
+        assert_eq!(grid.step((2, 1), Direction::South), None);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_views() {
+        let mut grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<_>>(),
+            vec![6, 3]
+        );
+        assert_eq!(grid.rows().len(), 2);
+
+        grid.column_mut(0).for_each(|cell| *cell *= 10);
+        grid.row_mut(0)[2] = 0;
+        assert_eq!(grid.to_string(), "1020\n4056\n");
+    }
+
+    #[test]
+    fn test_rotation() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+}
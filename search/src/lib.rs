@@ -0,0 +1,258 @@
+//! Searches over graphs given implicitly by a successor function.
+//!
+//! Nodes are whatever the day needs them to be, e.g. a position or a position along with the
+//! heading, and only the part of the graph reachable from the start is ever built.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Costs of edges and paths, where `Default` is the cost of the empty path.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// What a search found: the distance to every node it reached and how it got there.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub start: N,
+    pub distances: HashMap<N, C>,
+    /// The node each reached node was reached from, which the start has none of.
+    pub predecessors: HashMap<N, N>,
+    /// The goal the search stopped at, if it was looking for one and reached it.
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new(start: N) -> Self {
+        Search {
+            start,
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The nodes from the start to `node`, both included, unless it wasn't reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal and its cost, unless none was reached.
+    pub fn goal_path(&self) -> Option<(Vec<N>, C)> {
+        let goal = self.goal.as_ref()?;
+        Some((self.path_to(goal)?, self.distance(goal)?))
+    }
+}
+
+/// Visits every node reachable from `start`, counting the steps to each.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    search.distances.insert(start.clone(), 0);
+
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = search.distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// Finds the cheapest path from `start` to a node that `is_goal`, given the cost of every edge.
+///
+/// Without a goal, i.e. when `is_goal` is always false, it finds the cheapest path to every node.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so the heap pops the cheapest first
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` estimate of the cost left to reach a goal.
+///
+/// The path found is only the cheapest if the heuristic never overestimates, and nodes are only
+/// expanded once if it's consistent too, like the Manhattan distance on a grid.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone());
+    search.distances.insert(start.clone(), C::default());
+
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if search.distances[&node] < cost {
+            // Reached more cheaply since it was queued
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, edge_cost) in successors(&node) {
+            let next_cost = cost + edge_cost;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+
+            search.distances.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 5x5 grid with a wall down the middle column, open only in the bottom row.
+    fn open(&(row, col): &(i32, i32)) -> bool {
+        (0..5).contains(&row) && (0..5).contains(&col) && (col != 2 || row == 4)
+    }
+
+    fn neighbours(&(row, col): &(i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+        [
+            (row - 1, col),
+            (row, col + 1),
+            (row + 1, col),
+            (row, col - 1),
+        ]
+        .into_iter()
+        .filter(open)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs((0, 0), neighbours);
+        assert_eq!(search.distance(&(0, 4)), Some(12));
+        assert_eq!(search.distance(&(0, 2)), None);
+        assert_eq!(search.distances.len(), 21);
+        assert_eq!(search.goal, None);
+
+        let path = search.path_to(&(4, 3)).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 3)));
+        assert!(path
+            .windows(2)
+            .all(|w| neighbours(&w[0]).any(|n| n == w[1])));
+        assert_eq!(search.path_to(&(0, 2)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going through `b` takes more edges but costs less
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![('e', 1)]),
+            ('e', vec![]),
+        ]);
+        let successors = |node: &char| edges[node].clone();
+
+        let search = dijkstra('a', successors, |&node| node == 'd');
+        assert_eq!(search.goal_path(), Some((vec!['a', 'b', 'c', 'd'], 6)));
+        assert_eq!(search.distance(&'e'), None);
+
+        let search = dijkstra('a', successors, |_| false);
+        assert_eq!(search.goal_path(), None);
+        assert_eq!(search.distance(&'e'), Some(7));
+        assert_eq!(search.path_to(&'a'), Some(vec!['a']));
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (0, 4);
+        let successors = |node: &(i32, i32)| neighbours(node).map(|n| (n, 1));
+        let manhattan = |&(row, col): &(i32, i32)| (row - goal.0).abs() + (col - goal.1).abs();
+
+        let guided = astar((0, 0), successors, manhattan, |&node| node == goal);
+        let unguided = dijkstra((0, 0), successors, |&node| node == goal);
+        assert_eq!(guided.distance(&goal), Some(12));
+        assert_eq!(guided.goal_path().unwrap().0.len(), 13);
+        assert_eq!(unguided.distance(&goal), Some(12));
+    }
+}